@@ -0,0 +1,313 @@
+use std::fmt;
+
+use reqwest::{header::CONTENT_TYPE, Client, Method, Response};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use url::Url;
+
+use crate::client::error::{Error, Result};
+use crate::client::{
+    endpoint_url, normalize_host, secret_not_found, vault_api_error, Auth, DefaultSecretType,
+    ListResponse, SecretContainer, SecretData, SecretDataWrapper, TokenData, TokenOptions,
+    VaultResponse, DEFAULT_API_PREFIX, REDACTED,
+};
+use crate::TryInto;
+
+/// Asynchronous vault client, built on `reqwest`'s async `Client`.
+///
+/// This supports the most common operations of `VaultClient`; the futures it
+/// returns must be run on a Tokio runtime.
+///
+/// ```no_run
+/// # extern crate hashicorp_vault as vault;
+/// # extern crate tokio;
+/// use vault::client::AsyncVaultClient;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let host = "http://127.0.0.1:8200";
+/// let token = "test12345";
+/// let client = AsyncVaultClient::new(host, token).await.unwrap();
+/// client.set_secret("hello_async", "world").await.unwrap();
+/// assert_eq!(client.get_secret("hello_async").await.unwrap(), "world");
+/// # }
+/// ```
+pub struct AsyncVaultClient {
+    /// URL to vault instance, normalized so its path ends with `/`
+    pub host: Url,
+    /// Token to access vault
+    token: String,
+    /// `reqwest::Client`
+    client: Client,
+    /// Token data, if the token was looked up on construction
+    pub data: Option<VaultResponse<TokenData>>,
+    /// The secret backend name. Defaults to 'secret'
+    secret_backend: String,
+    /// Vault Enterprise namespace to send requests to
+    namespace: Option<String>,
+    /// Send the `X-Vault-Request` header with every request
+    request_header: bool,
+    /// Path prefix of the API, `/v1` unless overridden
+    api_prefix: String,
+}
+
+impl fmt::Debug for AsyncVaultClient {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AsyncVaultClient")
+            .field("host", &self.host.as_str())
+            .field("token", &REDACTED)
+            .field("data", &self.data.as_ref().map(|_| REDACTED))
+            .field("secret_backend", &self.secret_backend)
+            .field("namespace", &self.namespace)
+            .field("request_header", &self.request_header)
+            .field("api_prefix", &self.api_prefix)
+            .finish()
+    }
+}
+
+impl AsyncVaultClient {
+    /// Construct an `AsyncVaultClient` from an existing vault token
+    pub async fn new<U, T: Into<String>>(host: U, token: T) -> Result<AsyncVaultClient>
+    where
+        U: TryInto<Url, Err = Error>,
+    {
+        AsyncVaultClient::new_from_reqwest(host, token, Client::new()).await
+    }
+
+    /// Construct an `AsyncVaultClient` from an existing vault token and
+    /// async `reqwest::Client`
+    pub async fn new_from_reqwest<U, T: Into<String>>(
+        host: U,
+        token: T,
+        client: Client,
+    ) -> Result<AsyncVaultClient>
+    where
+        U: TryInto<Url, Err = Error>,
+    {
+        let mut vault = AsyncVaultClient {
+            host: normalize_host(host.try_into()?)?,
+            token: token.into(),
+            client,
+            data: None,
+            secret_backend: "secret".into(),
+            namespace: None,
+            request_header: false,
+            api_prefix: DEFAULT_API_PREFIX.into(),
+        };
+        vault.data = Some(vault.lookup().await?);
+        Ok(vault)
+    }
+
+    /// Construct an `AsyncVaultClient` without looking up the token.  See
+    /// `VaultClient::new_no_lookup`.
+    pub fn new_no_lookup<U, S: Into<String>>(host: U, token: S) -> Result<AsyncVaultClient>
+    where
+        U: TryInto<Url, Err = Error>,
+    {
+        Ok(AsyncVaultClient {
+            host: normalize_host(host.try_into()?)?,
+            token: token.into(),
+            client: Client::new(),
+            data: None,
+            secret_backend: "secret".into(),
+            namespace: None,
+            request_header: false,
+            api_prefix: DEFAULT_API_PREFIX.into(),
+        })
+    }
+
+    /// Set the backend name to be used by this client
+    pub fn secret_backend<S: Into<String>>(&mut self, backend_name: S) {
+        self.secret_backend = backend_name.into();
+    }
+
+    /// Set the Vault Enterprise namespace that requests are sent to
+    pub fn set_namespace<S: Into<String>>(&mut self, namespace: S) {
+        self.namespace = Some(namespace.into());
+    }
+
+    /// Send the `X-Vault-Request: true` header with every request.  See
+    /// `VaultClient::require_request_header`.
+    pub fn require_request_header(&mut self, required: bool) {
+        self.request_header = required;
+    }
+
+    /// Override the `/v1` path prefix of the API.  See
+    /// `VaultClient::set_api_prefix`.
+    pub fn set_api_prefix<S: Into<String>>(&mut self, prefix: S) {
+        self.api_prefix = prefix.into();
+    }
+
+    /// The token used to authenticate requests to vault
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// Lookup token information for this client's token.  See
+    /// `VaultClient::lookup`.
+    pub async fn lookup(&self) -> Result<VaultResponse<TokenData>> {
+        let res = self
+            .request(Method::GET, "/v1/auth/token/lookup-self", None)
+            .await?;
+        parse_vault_response(res).await
+    }
+
+    /// Create a new token with the given options.  See
+    /// `VaultClient::create_token`.
+    pub async fn create_token(&self, opts: &TokenOptions) -> Result<Auth> {
+        let body = serde_json::to_string(opts)?;
+        let res = self
+            .request(Method::POST, "/v1/auth/token/create", Some(body))
+            .await?;
+        let vault_res: VaultResponse<()> = parse_vault_response(res).await?;
+        vault_res
+            .auth
+            .ok_or_else(|| Error::Vault("Created token did not include auth data".into()))
+    }
+
+    /// Saves a secret.  See `VaultClient::set_secret`.
+    pub async fn set_secret<S1: Into<String>, S2: AsRef<str>>(
+        &self,
+        key: S1,
+        value: S2,
+    ) -> Result<()> {
+        let secret = DefaultSecretType {
+            value: value.as_ref(),
+        };
+        self.set_custom_secret(key, &secret).await
+    }
+
+    /// Saves a secret of any serializable type.  See
+    /// `VaultClient::set_custom_secret`.
+    pub async fn set_custom_secret<S1, S2>(&self, secret_name: S1, secret: &S2) -> Result<()>
+    where
+        S1: Into<String>,
+        S2: Serialize,
+    {
+        let json = serde_json::to_string(&SecretContainer { data: secret })?;
+        let _ = self
+            .request(
+                Method::PUT,
+                &format!("/v1/{}/data/{}", self.secret_backend, secret_name.into()),
+                Some(json),
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Fetches a saved secret.  See `VaultClient::get_secret`.
+    pub async fn get_secret<S: AsRef<str>>(&self, key: S) -> Result<String> {
+        let secret: SecretData = self.get_custom_secret(key).await?;
+        Ok(secret.value)
+    }
+
+    /// Fetches a saved secret of any deserializable type.  See
+    /// `VaultClient::get_custom_secret`.
+    pub async fn get_custom_secret<S: AsRef<str>, D: DeserializeOwned>(
+        &self,
+        secret_name: S,
+    ) -> Result<D> {
+        let key = secret_name.as_ref();
+        let res = self
+            .request(
+                Method::GET,
+                &format!("/v1/{}/data/{}", self.secret_backend, key),
+                None,
+            )
+            .await
+            .map_err(|e| secret_not_found(e, key))?;
+        let decoded: VaultResponse<SecretDataWrapper<D>> = parse_vault_response(res).await?;
+        match decoded.data {
+            Some(data) => Ok(data.data),
+            _ => Err(Error::Vault(format!(
+                "No secret found in response to request `{}`",
+                decoded.request_id.unwrap_or_default()
+            ))),
+        }
+    }
+
+    /// Lists secrets at a path.  See `VaultClient::list_secrets`.
+    pub async fn list_secrets<S: AsRef<str>>(&self, key: S) -> Result<Vec<String>> {
+        let res = self
+            .request(
+                Method::from_bytes(b"LIST").expect("Failed to parse LIST to Method"),
+                &format!("/v1/{}/metadata/{}", self.secret_backend, key.as_ref()),
+                None,
+            )
+            .await?;
+        let decoded: VaultResponse<ListResponse> = parse_vault_response(res).await?;
+        match decoded.data {
+            Some(data) => Ok(data.keys),
+            _ => Err(Error::Vault(format!(
+                "No secrets found in response: `{:#?}`",
+                decoded
+            ))),
+        }
+    }
+
+    /// Deletes a saved secret.  See `VaultClient::delete_secret`.
+    pub async fn delete_secret(&self, key: &str) -> Result<()> {
+        let _ = self
+            .request(
+                Method::DELETE,
+                &format!("/v1/{}/data/{}", self.secret_backend, key),
+                None,
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn request(
+        &self,
+        method: Method,
+        endpoint: &str,
+        body: Option<String>,
+    ) -> Result<Response> {
+        let mut req = self
+            .client
+            .request(
+                method,
+                endpoint_url(&self.host, &self.api_prefix, endpoint)?,
+            )
+            .header("X-Vault-Token", self.token.to_string());
+        if let Some(ref namespace) = self.namespace {
+            req = req.header("X-Vault-Namespace", namespace.as_str());
+        }
+        if self.request_header {
+            req = req.header("X-Vault-Request", "true");
+        }
+        if let Some(body) = body {
+            req = req.header(CONTENT_TYPE, "application/json").body(body);
+        }
+        handle_reqwest_response(req.send().await).await
+    }
+}
+
+/// helper fn to check `Response` for success.  An unsuccessful response is
+/// mapped by `vault_api_error`, as in the blocking client.
+async fn handle_reqwest_response(res: reqwest::Result<Response>) -> Result<Response> {
+    let res = res?;
+    if res.status().is_success() {
+        return Ok(res);
+    }
+    let status = res.status().as_u16();
+    match res.bytes().await {
+        Ok(body) => Err(vault_api_error(status, &body)),
+        // keep the status even without a body
+        Err(e) => Err(Error::VaultApi {
+            status,
+            errors: vec![format!("error message could not be read: {}", e)],
+        }),
+    }
+}
+
+/// helper fn to parse a vault response from an async `Response`
+async fn parse_vault_response<T>(res: Response) -> Result<T>
+where
+    T: DeserializeOwned,
+{
+    let body = res.bytes().await?;
+    // the body may hold secrets, so only log its size
+    trace!("Response: {} bytes", body.len());
+    Ok(serde_json::from_slice(&body)?)
+}
@@ -0,0 +1,248 @@
+use std::time::Duration;
+
+use reqwest::blocking::Client;
+use url::Url;
+
+use crate::client::error::{Error, Result};
+use crate::client::{
+    http_client_builder, AppRolePayload, KubernetesPayload, TokenData, VaultClient,
+    DEFAULT_API_PREFIX,
+};
+use crate::TryInto;
+
+/// Builder for a `VaultClient`, for when the configuration needed doesn't
+/// fit one of the `VaultClient::new*` constructors.
+///
+/// Configure the connection, then finish with the auth method to use:
+///
+/// ```
+/// # extern crate hashicorp_vault as vault;
+/// # use vault::client::VaultClientBuilder;
+/// use std::time::Duration;
+///
+/// let host = "http://127.0.0.1:8200";
+/// let token = "test12345";
+/// let client = VaultClientBuilder::new(host)
+///     .unwrap()
+///     .timeout(Duration::from_secs(10))
+///     .token(token)
+///     .unwrap();
+/// ```
+#[derive(Debug)]
+pub struct VaultClientBuilder {
+    host: Url,
+    client: Option<Client>,
+    timeout: Option<Duration>,
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Duration>,
+    namespace: Option<String>,
+    request_header: bool,
+    api_prefix: String,
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    root_certificates: Vec<reqwest::Certificate>,
+}
+
+impl VaultClientBuilder {
+    /// Start building a `VaultClient` for the vault at `host`
+    pub fn new<U>(host: U) -> Result<VaultClientBuilder>
+    where
+        U: TryInto<Url, Err = Error>,
+    {
+        Ok(VaultClientBuilder {
+            host: host.try_into()?,
+            client: None,
+            timeout: None,
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            namespace: None,
+            request_header: false,
+            api_prefix: DEFAULT_API_PREFIX.into(),
+            #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+            root_certificates: Vec::new(),
+        })
+    }
+
+    /// Use a pre-configured `reqwest::Client` to make requests.  When set,
+    /// `timeout`, the pool settings and `add_root_certificate` are ignored;
+    /// configure them on `client` instead, along with
+    /// `redirect(Policy::none())` to have standby redirects followed as
+    /// described on `Error::Standby`.
+    ///
+    /// A `reqwest::Client` holds its connection pool behind an `Arc`, so it
+    /// is cheap to clone, and clones share the pool.  Passing the client of
+    /// an existing `VaultClient` lets several clients, e.g. logged in with
+    /// different auth methods, reuse the same connections:
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::client::VaultClientBuilder;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let first = VaultClientBuilder::new(host).unwrap().token("test12345").unwrap();
+    /// let second = VaultClientBuilder::new(host)
+    ///     .unwrap()
+    ///     .client(first.http_client().clone())
+    ///     .token("test12345")
+    ///     .unwrap();
+    /// ```
+    pub fn client(mut self, client: Client) -> VaultClientBuilder {
+        self.client = Some(client);
+        self
+    }
+
+    /// Set the timeout applied to each request to vault, including the one
+    /// made to authenticate.
+    pub fn timeout(mut self, timeout: Duration) -> VaultClientBuilder {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set the maximum number of idle connections to vault kept open for
+    /// reuse.  Raise it for services making many concurrent requests, e.g.
+    /// transit operations.
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> VaultClientBuilder {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Set how long idle connections to vault are kept open for reuse.
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> VaultClientBuilder {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Send requests, including the one made to authenticate, to the given
+    /// Vault Enterprise namespace.
+    pub fn namespace<S: Into<String>>(mut self, namespace: S) -> VaultClientBuilder {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Send the `X-Vault-Request: true` header with every request, including
+    /// the one made to authenticate, as required by Vault Agent and some
+    /// reverse proxies in front of vault.  Defaults to `false`.
+    pub fn require_request_header(mut self, required: bool) -> VaultClientBuilder {
+        self.request_header = required;
+        self
+    }
+
+    /// Override the `/v1` path prefix of the API, including for the request
+    /// made to authenticate, e.g. for a proxy which rewrites paths.  An empty
+    /// prefix sends requests to the host's path directly.
+    pub fn api_prefix<S: Into<String>>(mut self, prefix: S) -> VaultClientBuilder {
+        self.api_prefix = prefix.into();
+        self
+    }
+
+    /// Trust an additional root certificate, e.g. the CA certificate of a
+    /// vault with a self-signed certificate.
+    ///
+    /// ```no_run
+    /// # extern crate hashicorp_vault as vault;
+    /// # extern crate reqwest;
+    /// # use vault::client::VaultClientBuilder;
+    ///
+    /// let pem = std::fs::read("/path/to/vault-ca.pem").unwrap();
+    /// let ca = reqwest::Certificate::from_pem(&pem).unwrap();
+    /// let client = VaultClientBuilder::new("https://vault.example.com:8200")
+    ///     .unwrap()
+    ///     .add_root_certificate(ca)
+    ///     .token("test12345")
+    ///     .unwrap();
+    /// ```
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    pub fn add_root_certificate(mut self, cert: reqwest::Certificate) -> VaultClientBuilder {
+        self.root_certificates.push(cert);
+        self
+    }
+
+    /// Finish building a `VaultClient` from an existing vault token.  See
+    /// `VaultClient::new`.
+    pub fn token<S: Into<String>>(self, token: S) -> Result<VaultClient<TokenData>> {
+        let client = self.build_client()?;
+        VaultClient::connect(
+            self.host,
+            token.into(),
+            client,
+            self.namespace,
+            self.request_header,
+            self.api_prefix,
+        )
+    }
+
+    /// Finish building a `VaultClient` by logging in via the `AppRole`
+    /// [auth backend](https://www.vaultproject.io/docs/auth/approle.html).
+    /// See `VaultClient::new_app_role`.
+    pub fn app_role<R, S>(self, role_id: R, secret_id: Option<S>) -> Result<VaultClient<()>>
+    where
+        R: Into<String>,
+        S: Into<String>,
+    {
+        let payload = AppRolePayload {
+            role_id: role_id.into(),
+            secret_id: secret_id.map(|s| s.into()),
+        };
+        let client = self.build_client()?;
+        VaultClient::login(
+            self.host,
+            client,
+            self.namespace,
+            self.request_header,
+            self.api_prefix,
+            "/v1/auth/approle/login",
+            &payload,
+        )
+    }
+
+    /// Finish building a `VaultClient` by logging in via the `Kubernetes`
+    /// [auth backend](https://www.vaultproject.io/docs/auth/kubernetes).
+    /// `mountpoint` defaults to `kubernetes`.  See
+    /// `VaultClient::new_kubernetes`.
+    pub fn kubernetes<R, J>(
+        self,
+        role: R,
+        jwt: J,
+        mountpoint: Option<String>,
+    ) -> Result<VaultClient<()>>
+    where
+        R: Into<String>,
+        J: Into<String>,
+    {
+        let path = mountpoint.unwrap_or_else(|| "kubernetes".to_owned());
+        let payload = KubernetesPayload {
+            role: role.into(),
+            jwt: jwt.into(),
+        };
+        let client = self.build_client()?;
+        VaultClient::login(
+            self.host,
+            client,
+            self.namespace,
+            self.request_header,
+            self.api_prefix,
+            &format!("/v1/auth/{}/login", path),
+            &payload,
+        )
+    }
+
+    fn build_client(&self) -> Result<Client> {
+        if let Some(ref client) = self.client {
+            return Ok(client.clone());
+        }
+        let mut builder = http_client_builder();
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        if let Some(timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+        #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+        for cert in &self.root_certificates {
+            builder = builder.add_root_certificate(cert.clone());
+        }
+        Ok(builder.build()?)
+    }
+}
@@ -1,3 +1,5 @@
+use reqwest::StatusCode;
+
 /// `Result` type-alias
 pub type Result<T> = ::std::result::Result<T, Error>;
 
@@ -22,10 +24,41 @@ quick_error! {
             display("vault error: {}", err)
         }
         /// Response from Vault errors
-        /// This is for when the response is not successful.
-        VaultResponse(err: String, response: reqwest::blocking::Response) {
+        /// This is for when the response is not successful, and its body
+        /// could not be read.
+        VaultResponse(err: String, response: Box<reqwest::blocking::Response>) {
             display("Error in vault response: {}", err)
         }
+        /// Vault rejected a request, returning its structured error
+        /// messages.  `status` is the HTTP status code of the response and
+        /// `errors` the messages vault returned, which may be empty.  A
+        /// body which isn't a vault error, e.g. from a proxy, is returned
+        /// as the single message.
+        #[allow(missing_docs)]
+        VaultApi { status: u16, errors: Vec<String> } {
+            display("vault API error (status {}): {}", status, errors.join("; "))
+        }
+        /// A standby vault server redirected the request to the active
+        /// node at `location`, and the redirect could not be followed.  A
+        /// client follows one such redirect itself, sending its token to
+        /// the active node, so this is returned when the active node
+        /// redirects again, e.g. during a leader election.
+        Standby(location: String) {
+            display("vault server is a standby, active node is at {}", location)
+        }
+        /// The requested secret does not exist
+        NotFound(key: String) {
+            display("secret `{}` not found", key)
+        }
+        /// A check-and-set write of the KV v2 secret `key` was rejected
+        /// because the secret's current version didn't match
+        CasMismatch(key: String) {
+            display("check-and-set version of secret `{}` did not match", key)
+        }
+        /// A token or lease is not renewable, so renewal was not attempted
+        NotRenewable(what: String) {
+            display("{} is not renewable", what)
+        }
         /// IO errors
         Io(err: ::std::io::Error) {
             from()
@@ -46,3 +79,55 @@ quick_error! {
         }
     }
 }
+
+impl Error {
+    /// The HTTP status code vault responded with, if this error came from
+    /// an unsuccessful response.
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # extern crate reqwest;
+    /// # use vault::Client;
+    /// use reqwest::StatusCode;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// let err = client.list_secrets("non/existent/key").unwrap_err();
+    /// assert_eq!(err.status(), Some(StatusCode::NOT_FOUND));
+    /// assert!(err.is_not_found());
+    /// ```
+    pub fn status(&self) -> Option<StatusCode> {
+        match *self {
+            Error::VaultApi { status, .. } => StatusCode::from_u16(status).ok(),
+            Error::VaultResponse(_, ref res) => Some(res.status()),
+            Error::NotFound(_) => Some(StatusCode::NOT_FOUND),
+            Error::Reqwest(ref err) => err.status(),
+            _ => None,
+        }
+    }
+
+    /// True if vault responded with `404 Not Found`
+    pub fn is_not_found(&self) -> bool {
+        self.status() == Some(StatusCode::NOT_FOUND)
+    }
+
+    /// True if vault responded with `403 Forbidden`, i.e. the token is
+    /// invalid or lacks the required capabilities
+    pub fn is_permission_denied(&self) -> bool {
+        self.status() == Some(StatusCode::FORBIDDEN)
+    }
+
+    /// True if vault responded with `429 Too Many Requests`, i.e. the
+    /// request was rate limited
+    pub fn is_rate_limited(&self) -> bool {
+        self.status() == Some(StatusCode::TOO_MANY_REQUESTS)
+    }
+
+    /// True if vault responded with `503 Service Unavailable`, e.g. because
+    /// it is sealed
+    pub fn is_unavailable(&self) -> bool {
+        self.status() == Some(StatusCode::SERVICE_UNAVAILABLE)
+    }
+}
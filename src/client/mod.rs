@@ -1,30 +1,42 @@
 use std::collections::HashMap;
 use std::fmt;
-use std::io::Read;
+use std::fs;
+use std::io::{BufReader, Read};
 use std::num::NonZeroU64;
 use std::result::Result as StdResult;
 use std::str::FromStr;
+use std::sync::{Mutex, MutexGuard};
 
 use crate::client::error::{Error, Result};
 use base64;
 use reqwest::{
     self,
-    blocking::{Client, Response},
-    header::CONTENT_TYPE,
-    Method,
+    blocking::{Client, ClientBuilder, RequestBuilder, Response},
+    header::{CONTENT_TYPE, LOCATION},
+    redirect::Policy,
+    Method, StatusCode,
 };
 use serde::de::{self, DeserializeOwned, Visitor};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::TryInto;
+use crate::{TryFrom, TryInto};
 use chrono::{DateTime, FixedOffset, NaiveDateTime};
 use serde_json;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use url::Url;
 
 /// Errors
 pub mod error;
 
+mod async_client;
+mod builder;
+pub use self::async_client::AsyncVaultClient;
+pub use self::builder::VaultClientBuilder;
+
+/// Location of the service account token mounted into Kubernetes pods
+const KUBERNETES_SERVICE_ACCOUNT_TOKEN: &str =
+    "/var/run/secrets/kubernetes.io/serviceaccount/token";
+
 /// Lease duration.
 ///
 /// Note: Value returned from vault api is assumed to be in seconds.
@@ -35,7 +47,7 @@ pub mod error;
 /// assert_eq!(VaultDuration::days(1),
 ///            VaultDuration(std::time::Duration::from_secs(86400)));
 /// ```
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct VaultDuration(pub Duration);
 
 impl VaultDuration {
@@ -92,6 +104,56 @@ impl<'de> Deserialize<'de> for VaultDuration {
     }
 }
 
+/// Time-to-live of a response-wrapping token, sent in the
+/// `X-Vault-Wrap-TTL` header.  Durations are sent in seconds; strings are
+/// sent as-is, for any other format vault accepts.
+///
+/// ```
+/// use hashicorp_vault::client::{VaultDuration, WrapTtl};
+///
+/// assert_eq!(WrapTtl::from(VaultDuration::minutes(2)).as_str(), "120s");
+/// assert_eq!(WrapTtl::from("2m").as_str(), "2m");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WrapTtl(String);
+
+impl WrapTtl {
+    /// The value sent in the `X-Vault-Wrap-TTL` header
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<VaultDuration> for WrapTtl {
+    fn from(ttl: VaultDuration) -> Self {
+        WrapTtl(format!("{}s", ttl.0.as_secs()))
+    }
+}
+
+impl From<Duration> for WrapTtl {
+    fn from(ttl: Duration) -> Self {
+        VaultDuration(ttl).into()
+    }
+}
+
+impl From<&str> for WrapTtl {
+    fn from(ttl: &str) -> Self {
+        WrapTtl(ttl.to_owned())
+    }
+}
+
+impl From<String> for WrapTtl {
+    fn from(ttl: String) -> Self {
+        WrapTtl(ttl)
+    }
+}
+
+impl From<&String> for WrapTtl {
+    fn from(ttl: &String) -> Self {
+        WrapTtl(ttl.clone())
+    }
+}
+
 /// Number of uses to be used with tokens.
 ///
 /// Note: Value returned from vault api can be 0 which means unlimited.
@@ -107,9 +169,10 @@ impl<'de> Deserialize<'de> for VaultDuration {
 ///     VaultNumUses::Unlimited => panic!("Uses shouldn't be unlimited!"),
 /// }
 /// ```
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub enum VaultNumUses {
     /// The number of uses is unlimited
+    #[default]
     Unlimited,
 
     /// The number of uses is limited to the value
@@ -117,12 +180,6 @@ pub enum VaultNumUses {
     Limited(NonZeroU64),
 }
 
-impl Default for VaultNumUses {
-    fn default() -> Self {
-        VaultNumUses::Unlimited
-    }
-}
-
 impl From<u64> for VaultNumUses {
     fn from(v: u64) -> Self {
         match NonZeroU64::new(v) {
@@ -139,7 +196,7 @@ impl Serialize for VaultNumUses {
     {
         match self {
             VaultNumUses::Unlimited => serializer.serialize_u64(0),
-            VaultNumUses::Limited(val) => serializer.serialize_u64(val.clone().into()),
+            VaultNumUses::Limited(val) => serializer.serialize_u64(val.get()),
         }
     }
 }
@@ -183,7 +240,7 @@ impl<'de> Visitor<'de> for VaultNaiveDateTimeVisitor {
     where
         E: de::Error,
     {
-        let date_time = NaiveDateTime::from_timestamp_opt(value as i64, 0);
+        let date_time = DateTime::from_timestamp(value as i64, 0).map(|dt| dt.naive_utc());
 
         match date_time {
             Some(dt) => Ok(VaultNaiveDateTime(dt)),
@@ -239,23 +296,149 @@ impl<'de> Deserialize<'de> for VaultDateTime {
     }
 }
 
+/// Used for RFC 3339 timestamps which vault sends as an empty string when unset
+fn deserialize_optional_date_time<'de, D>(
+    deserializer: D,
+) -> StdResult<Option<VaultDateTime>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value: Option<String> = Option::deserialize(deserializer)?;
+    match value {
+        Some(ref s) if !s.is_empty() => VaultDateTimeVisitor.visit_str(s).map(Some),
+        _ => Ok(None),
+    }
+}
+
+/// Used for strings which vault sends as an empty string when unset
+fn deserialize_optional_string<'de, D>(deserializer: D) -> StdResult<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value: Option<String> = Option::deserialize(deserializer)?;
+    Ok(value.filter(|s| !s.is_empty()))
+}
+
+/// Used for CIDR lists, which vault accepts, and older versions return, as a
+/// comma-separated string as well as an array
+fn deserialize_cidr_list<'de, D>(deserializer: D) -> StdResult<Option<Vec<String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum CidrList {
+        List(Vec<String>),
+        Joined(String),
+    }
+    let value: Option<CidrList> = Option::deserialize(deserializer)?;
+    Ok(value.map(|cidrs| match cidrs {
+        CidrList::List(list) => list,
+        CidrList::Joined(joined) => joined
+            .split(',')
+            .map(str::trim)
+            .filter(|cidr| !cidr.is_empty())
+            .map(String::from)
+            .collect(),
+    }))
+}
+
+/// Sends CIDR lists as the comma-separated string every vault version accepts
+fn serialize_cidr_list<S>(cidrs: &Option<Vec<String>>, serializer: S) -> StdResult<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match cidrs {
+        Some(cidrs) => serializer.serialize_str(&cidrs.join(",")),
+        None => serializer.serialize_none(),
+    }
+}
+
 /// Vault client used to make API requests to the vault
-#[derive(Debug)]
+///
+/// The host is the address of the vault server, e.g.
+/// `https://vault.example.com:8200`, with an `http` or `https` scheme.  It may
+/// include a path prefix, e.g. `https://gateway/vault` for a vault behind a
+/// reverse proxy, which is prepended to every API path.  It is normalized so
+/// its path ends with `/`.
+///
+/// The `Debug` output of a client redacts its token and cached token data.
 pub struct VaultClient<T> {
-    /// URL to vault instance
+    /// URL to vault instance, normalized so its path ends with `/`
     pub host: Url,
     /// Token to access vault
-    pub token: String,
+    token: String,
     /// `reqwest::Client`
     client: Client,
     /// Data
     pub data: Option<VaultResponse<T>>,
     /// The secret backend name. Defaults to 'secret'
     secret_backend: String,
+    /// Check that tokens and leases are renewable before renewing them
+    strict_renewal: bool,
+    /// Vault Enterprise namespace to send requests to
+    namespace: Option<String>,
+    /// Send the `X-Vault-Request` header with every request
+    request_header: bool,
+    /// Path prefix of the API, `/v1` unless overridden
+    api_prefix: String,
+    /// Number of times to retry idempotent requests which fail with a 5xx
+    max_retries: u32,
+    /// Delay before the first retry, doubled for each subsequent retry
+    retry_backoff: Duration,
+    /// Timeout applied to each request, overriding the `reqwest::Client`'s
+    timeout: Option<Duration>,
+    /// Cache for `get_secret_cached`, if enabled
+    secret_cache: Option<SecretCache>,
+}
+
+/// In-memory cache of secret values, keyed by path
+#[derive(Debug)]
+struct SecretCache {
+    /// How long to cache secrets which have no lease duration, e.g. KV v2
+    default_ttl: Duration,
+    /// Secret values and when they expire
+    entries: Mutex<HashMap<SecretCacheKey, (Instant, String)>>,
+}
+
+/// Namespace, secret backend and key a cached secret was read from
+type SecretCacheKey = (Option<String>, String, String);
+
+impl SecretCache {
+    fn lock(&self) -> MutexGuard<'_, HashMap<SecretCacheKey, (Instant, String)>> {
+        // the map is always left consistent, so a panic elsewhere while it
+        // was locked doesn't invalidate it
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl<T> fmt::Debug for VaultClient<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("VaultClient")
+            .field("host", &self.host.as_str())
+            .field("token", &REDACTED)
+            .field("data", &self.data.as_ref().map(|_| REDACTED))
+            .field("secret_backend", &self.secret_backend)
+            .field("strict_renewal", &self.strict_renewal)
+            .field("namespace", &self.namespace)
+            .field("request_header", &self.request_header)
+            .field("api_prefix", &self.api_prefix)
+            .field("max_retries", &self.max_retries)
+            .field("retry_backoff", &self.retry_backoff)
+            .field("timeout", &self.timeout)
+            .field("secret_cache", &self.secret_cache.is_some())
+            .finish()
+    }
 }
 
+/// Path prefix of the vault API
+const DEFAULT_API_PREFIX: &str = "/v1";
+
+/// Placeholder for secrets in `Debug` output
+const REDACTED: &str = "<redacted>";
+
 /// Token data, used in `VaultResponse`
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize)]
 pub struct TokenData {
     /// Accessor token
     pub accessor: Option<String>,
@@ -289,6 +472,28 @@ pub struct TokenData {
     pub ttl: VaultDuration,
 }
 
+impl fmt::Debug for TokenData {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TokenData")
+            .field("accessor", &self.accessor)
+            .field("creation_time", &self.creation_time)
+            .field("creation_ttl", &self.creation_ttl)
+            .field("display_name", &self.display_name)
+            .field("explicit_max_ttl", &self.explicit_max_ttl)
+            .field("id", &REDACTED)
+            .field("last_renewal_time", &self.last_renewal_time)
+            .field("meta", &self.meta)
+            .field("num_uses", &self.num_uses)
+            .field("orphan", &self.orphan)
+            .field("path", &self.path)
+            .field("policies", &self.policies)
+            .field("renewable", &self.renewable)
+            .field("role", &self.role)
+            .field("ttl", &self.ttl)
+            .finish()
+    }
+}
+
 /// Secret data, used in `VaultResponse`
 ///
 /// This struct should onlly ever be necessary for advanced users who
@@ -299,26 +504,432 @@ pub struct SecretDataWrapper<D> {
     pub data: D,
 }
 
+/// Metadata of a single version of a KV v2 secret
+#[derive(Deserialize, Debug)]
+pub struct KvV2Metadata {
+    /// Creation time of this version
+    pub created_time: VaultDateTime,
+    /// Deletion time of this version, if it has been deleted
+    #[serde(default, deserialize_with = "deserialize_optional_date_time")]
+    pub deletion_time: Option<VaultDateTime>,
+    /// True if this version has been permanently destroyed
+    pub destroyed: bool,
+    /// Version number
+    pub version: u64,
+}
+
+/// A KV v2 secret along with the metadata of the version that was read
+#[derive(Deserialize, Debug)]
+pub struct KvV2Secret<D> {
+    /// Secret data
+    pub data: D,
+    /// Metadata of this version of the secret
+    pub metadata: KvV2Metadata,
+}
+
+/// Metadata of every version of a KV v2 secret, along with its
+/// configuration
+#[derive(Deserialize, Debug)]
+pub struct KvV2FullMetadata {
+    /// Latest version of the secret
+    pub current_version: u64,
+    /// Oldest version of the secret still stored
+    pub oldest_version: u64,
+    /// Number of versions kept, or 0 to use the backend's setting
+    pub max_versions: u64,
+    /// True if writes must use check-and-set
+    pub cas_required: bool,
+    /// Creation time of the secret
+    pub created_time: VaultDateTime,
+    /// Time the secret was last updated
+    pub updated_time: VaultDateTime,
+    /// Metadata of each stored version, keyed by version number
+    pub versions: HashMap<String, KvV2VersionMetadata>,
+}
+
+/// Metadata of a single version of a KV v2 secret, as listed in
+/// `KvV2FullMetadata`
+#[derive(Deserialize, Debug)]
+pub struct KvV2VersionMetadata {
+    /// Creation time of this version
+    pub created_time: VaultDateTime,
+    /// Deletion time of this version, if it has been deleted
+    #[serde(default, deserialize_with = "deserialize_optional_date_time")]
+    pub deletion_time: Option<VaultDateTime>,
+    /// True if this version has been permanently destroyed
+    pub destroyed: bool,
+}
+
 /// Actual Secret data, used in `VaultResponse`
-#[derive(Deserialize, Serialize, Debug)]
-struct SecretData {
-    value: String,
+///
+/// This is the shape `set_secret` saves and `get_secret` reads, so it can be
+/// used to do either with the `custom` variants:
+///
+/// ```
+/// # extern crate hashicorp_vault as vault;
+/// # use vault::Client;
+/// use vault::client::SecretData;
+///
+/// let host = "http://127.0.0.1:8200";
+/// let token = "test12345";
+/// let client = Client::new(host, token).unwrap();
+/// let secret = SecretData { value: "world".to_owned() };
+/// client.set_custom_secret("hello_secret_data", &secret).unwrap();
+/// assert_eq!(client.get_secret("hello_secret_data").unwrap(), "world");
+/// ```
+#[derive(Deserialize, Serialize)]
+pub struct SecretData {
+    /// The secret's value, as saved by `set_secret`
+    pub value: String,
+}
+
+impl fmt::Debug for SecretData {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SecretData")
+            .field("value", &REDACTED)
+            .finish()
+    }
 }
 
 /// Transit decrypted data, used in `VaultResponse`
-#[derive(Deserialize, Serialize, Debug)]
-struct TransitDecryptedData {
-    plaintext: String,
+#[derive(Deserialize, Serialize)]
+pub struct TransitDecryptedData {
+    /// Base64 encoded plaintext
+    pub plaintext: String,
+}
+
+impl fmt::Debug for TransitDecryptedData {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TransitDecryptedData")
+            .field("plaintext", &REDACTED)
+            .finish()
+    }
 }
 
 /// Transit encrypted data, used in `VaultResponse`
+#[derive(Deserialize, Serialize)]
+pub struct TransitEncryptedData {
+    /// Ciphertext, in the `vault:v1:...` format
+    pub ciphertext: String,
+}
+
+impl fmt::Debug for TransitEncryptedData {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TransitEncryptedData")
+            .field("ciphertext", &REDACTED)
+            .finish()
+    }
+}
+
+/// Options for encrypting via Transit secret backend.
+///
+/// If an option is not specified, the latest version of the key is used,
+/// without key derivation.
+#[derive(Default, Debug)]
+pub struct TransitEncryptOptions {
+    context: Option<String>,
+    key_version: Option<u32>,
+}
+
+impl TransitEncryptOptions {
+    /// Derive the key from `context`.  The key must have been created with
+    /// `derived` set; see `VaultClient::transit_encrypt_with_context`.
+    pub fn context(mut self, context: &[u8]) -> Self {
+        self.context = Some(base64::encode(context));
+        self
+    }
+
+    /// Encrypt with the given key version rather than the latest, e.g.
+    /// during a controlled rotation.
+    pub fn key_version(mut self, version: u32) -> Self {
+        self.key_version = Some(version);
+        self
+    }
+}
+
+/// Payload to send to vault when encrypting via transit
+#[derive(Serialize)]
+struct TransitEncryptPayload {
+    plaintext: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    context: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    key_version: Option<u32>,
+}
+
+impl fmt::Debug for TransitEncryptPayload {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TransitEncryptPayload")
+            .field("plaintext", &REDACTED)
+            .field("context", &self.context)
+            .field("key_version", &self.key_version)
+            .finish()
+    }
+}
+
+/// Payload to send to vault when decrypting via transit
+#[derive(Serialize, Debug)]
+struct TransitDecryptPayload {
+    ciphertext: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    context: Option<String>,
+}
+
+/// Transit batch input or result item
+#[derive(Deserialize, Serialize, Default)]
+struct TransitBatchItem {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    plaintext: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ciphertext: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+impl fmt::Debug for TransitBatchItem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TransitBatchItem")
+            .field("plaintext", &self.plaintext.as_ref().map(|_| REDACTED))
+            .field("ciphertext", &self.ciphertext.as_ref().map(|_| REDACTED))
+            .field("error", &self.error)
+            .finish()
+    }
+}
+
+/// Payload to send to vault for transit batch operations
+#[derive(Serialize, Debug)]
+struct TransitBatchPayload {
+    batch_input: Vec<TransitBatchItem>,
+    partial_failure_response_code: u16,
+}
+
+/// Transit batch results, used in `VaultResponse`
+#[derive(Deserialize, Debug)]
+struct TransitBatchData {
+    batch_results: Vec<TransitBatchItem>,
+}
+
+/// Transit HMAC data, used in `VaultResponse`
+#[derive(Deserialize, Serialize, Debug)]
+pub struct TransitHmacData {
+    /// HMAC, in the `vault:v1:...` format
+    pub hmac: String,
+}
+
+/// Transit signature verification result, used in `VaultResponse`
+#[derive(Deserialize, Serialize, Debug)]
+pub struct TransitVerifyData {
+    /// True if the HMAC or signature is valid
+    pub valid: bool,
+}
+
+/// Payload to send to vault when generating or verifying an HMAC
+#[derive(Serialize, Debug)]
+struct TransitHmacPayload<'a> {
+    input: String,
+    algorithm: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hmac: Option<&'a str>,
+}
+
+/// Transit random bytes, used in `VaultResponse`
+#[derive(Deserialize, Serialize, Debug)]
+pub struct TransitRandomData {
+    /// Random bytes, in the requested encoding
+    pub random_bytes: String,
+}
+
+/// Payload to send to vault when generating random bytes
+#[derive(Serialize, Debug)]
+struct TransitRandomPayload {
+    format: RandomFormat,
+}
+
+/// Encoding vault uses to return random bytes
+#[derive(Clone, Copy, Serialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RandomFormat {
+    /// Base64 encoding
+    Base64,
+    /// Hex encoding
+    Hex,
+}
+
+impl RandomFormat {
+    fn decode(self, encoded: &str) -> Result<Vec<u8>> {
+        match self {
+            RandomFormat::Base64 => Ok(base64::decode(encoded)?),
+            RandomFormat::Hex => encoded
+                .as_bytes()
+                .chunks(2)
+                .map(|pair| {
+                    std::str::from_utf8(pair)
+                        .ok()
+                        .filter(|byte| byte.len() == 2)
+                        .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+                        .ok_or_else(|| {
+                            Error::Vault(format!("Invalid hex in response: `{}`", encoded))
+                        })
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Transit data key, used in `VaultResponse`
 #[derive(Deserialize, Serialize, Debug)]
-struct TransitEncryptedData {
+struct TransitDataKeyData {
     ciphertext: String,
+    plaintext: Option<String>,
 }
 
-/// Vault auth
+/// Payload to send to vault when generating a data key
+#[derive(Serialize, Debug)]
+struct TransitDataKeyPayload {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bits: Option<u32>,
+}
+
+/// A data key generated by the Transit secret backend, for envelope
+/// encryption
+#[derive(Clone, PartialEq, Eq)]
+pub struct DataKey {
+    /// The data key wrapped by the named transit key, in `vault:vN:` format
+    pub ciphertext: String,
+    /// The raw data key, only present when it was requested in plaintext
+    pub plaintext: Option<Vec<u8>>,
+}
+
+impl fmt::Debug for DataKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DataKey")
+            .field("ciphertext", &REDACTED)
+            .field("plaintext", &self.plaintext.as_ref().map(|_| REDACTED))
+            .finish()
+    }
+}
+
+/// Payload to send to vault when creating a transit key
+#[derive(Serialize, Debug)]
+struct TransitCreateKeyPayload<'a> {
+    #[serde(rename = "type")]
+    key_type: &'a str,
+}
+
+/// Exported transit key versions, returned from `:mount/export`
+#[derive(Deserialize)]
+struct TransitExportData {
+    keys: HashMap<String, String>,
+}
+
+impl fmt::Debug for TransitExportData {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TransitExportData")
+            .field("keys", &REDACTED)
+            .finish()
+    }
+}
+
+/// Settings to change on a transit key.
+///
+/// Settings which are not specified are left unchanged.
+#[derive(Default, Serialize, Debug)]
+pub struct TransitKeyConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_decryption_version: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_encryption_version: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    deletion_allowed: Option<bool>,
+}
+
+impl TransitKeyConfig {
+    /// The minimum key version that may still be used for decryption, e.g.
+    /// to retire key versions after a rotation.
+    pub fn min_decryption_version(mut self, version: u64) -> Self {
+        self.min_decryption_version = Some(version);
+        self
+    }
+
+    /// The minimum key version that may be used for encryption, `0` for the
+    /// latest version.
+    pub fn min_encryption_version(mut self, version: u64) -> Self {
+        self.min_encryption_version = Some(version);
+        self
+    }
+
+    /// Whether the key may be deleted.
+    pub fn deletion_allowed(mut self, allowed: bool) -> Self {
+        self.deletion_allowed = Some(allowed);
+        self
+    }
+}
+
+/// Transit key properties
 #[derive(Deserialize, Debug)]
+pub struct TransitKeyInfo {
+    /// Key versions, mapped to their creation time (symmetric keys) or to
+    /// their public key details (asymmetric keys)
+    pub keys: HashMap<String, serde_json::Value>,
+    /// Latest version of the key, used for new encryptions
+    pub latest_version: u64,
+    /// Minimum key version that may still be used for decryption
+    pub min_decryption_version: u64,
+    /// Minimum key version that may be used for encryption, `0` for the
+    /// latest version
+    #[serde(default)]
+    pub min_encryption_version: u64,
+    /// Type of the key, e.g. `aes256-gcm96`
+    #[serde(rename = "type")]
+    pub key_type: String,
+    /// Whether the key may be deleted
+    pub deletion_allowed: bool,
+}
+
+/// A Transit ciphertext along with the version of the key that produced it.
+///
+/// Parses from and formats to Vault's `vault:vN:<base64>` representation.
+///
+/// ```
+/// use hashicorp_vault::client::TransitCiphertext;
+///
+/// let ciphertext: TransitCiphertext = "vault:v2:AmFmYWJjYlVk".parse().unwrap();
+/// assert_eq!(ciphertext.version, 2);
+/// assert_eq!(ciphertext.bytes, b"\x02afabcbUd");
+/// assert_eq!(ciphertext.to_string(), "vault:v2:AmFmYWJjYlVk");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransitCiphertext {
+    /// Version of the key used for encryption
+    pub version: u32,
+    /// Raw encrypted bytes
+    pub bytes: Vec<u8>,
+}
+
+impl FromStr for TransitCiphertext {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<TransitCiphertext> {
+        let (version, encoded) = s
+            .strip_prefix("vault:v")
+            .and_then(|rest| rest.split_once(':'))
+            .and_then(|(version, encoded)| Some((version.parse::<u32>().ok()?, encoded)))
+            .ok_or_else(|| Error::Vault(format!("Unrecognized ciphertext format: `{:#?}`", s)))?;
+        Ok(TransitCiphertext {
+            version,
+            bytes: base64::decode(encoded)?,
+        })
+    }
+}
+
+impl fmt::Display for TransitCiphertext {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "vault:v{}:{}", self.version, base64::encode(&self.bytes))
+    }
+}
+
+/// Vault auth
+#[derive(Deserialize)]
 pub struct Auth {
     /// Client token id
     pub client_token: String,
@@ -334,12 +945,26 @@ pub struct Auth {
     pub renewable: bool,
 }
 
+impl fmt::Debug for Auth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Auth")
+            .field("client_token", &REDACTED)
+            .field("accessor", &self.accessor)
+            .field("policies", &self.policies)
+            .field("metadata", &self.metadata)
+            .field("lease_duration", &self.lease_duration)
+            .field("renewable", &self.renewable)
+            .finish()
+    }
+}
+
 /// Vault response. Different vault responses have different `data` types, so `D` is used to
 /// represent this.
 #[derive(Deserialize, Debug)]
 pub struct VaultResponse<D> {
-    /// Request id
-    pub request_id: String,
+    /// Request id, for finding the request in vault's audit log.  Missing
+    /// from the responses of older vault versions.
+    pub request_id: Option<String>,
     /// Lease id
     pub lease_id: Option<String>,
     /// True if renewable
@@ -372,7 +997,7 @@ impl<D> From<VaultResponse<SecretDataWrapper<D>>> for VaultResponse<D> {
 }
 
 /// Information provided to retrieve a wrapped response
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize)]
 pub struct WrapInfo {
     /// Time-to-live
     pub ttl: VaultDuration,
@@ -384,6 +1009,28 @@ pub struct WrapInfo {
     pub wrapped_accessor: Option<String>,
 }
 
+impl fmt::Debug for WrapInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("WrapInfo")
+            .field("ttl", &self.ttl)
+            .field("token", &REDACTED)
+            .field("creation_time", &self.creation_time)
+            .field("wrapped_accessor", &self.wrapped_accessor)
+            .finish()
+    }
+}
+
+/// Metadata of a wrapping token, as returned by `sys/wrapping/lookup`
+#[derive(Deserialize, Debug)]
+pub struct WrappingLookup {
+    /// Path of the request which created the wrapped response
+    pub creation_path: String,
+    /// Creation time, note this returned in RFC 3339 format
+    pub creation_time: VaultDateTime,
+    /// Time-to-live the wrapping token was created with
+    pub creation_ttl: VaultDuration,
+}
+
 /// Wrapped response is serialized json
 #[derive(Deserialize, Serialize, Debug)]
 pub struct WrapData {
@@ -392,7 +1039,7 @@ pub struct WrapData {
 }
 
 /// Token Types
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 #[serde(rename_all = "kebab-case")]
 pub enum TokenType {
     /// Batch tokens are encrypted blobs that carry enough information
@@ -413,14 +1060,22 @@ pub enum TokenType {
 }
 
 /// `AppRole` properties
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub struct AppRoleProperties {
     /// Require `secret_id` to be presented when logging in using this `AppRole`. Defaults to 'true'.
     pub bind_secret_id: bool,
     /// The secret IDs generated using this role will be cluster local.
-    pub local_secret_ids: bool,
+    /// Not reported by vault versions before 0.10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub local_secret_ids: Option<bool>,
     /// List of CIDR blocks; if set, specifies blocks of IP addresses which can
     /// perform the login operation.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_cidr_list",
+        serialize_with = "serialize_cidr_list",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub secret_id_bound_cidrs: Option<Vec<String>>,
     /// Number of times any particular `SecretID` can be used to fetch a token from this `AppRole`,
     /// after which the `SecretID` will expire.
@@ -429,12 +1084,20 @@ pub struct AppRoleProperties {
     pub secret_id_ttl: VaultDuration,
     /// List of CIDR blocks; if set, specifies blocks of IP addresses which can authenticate successfully,
     /// and ties the resulting token to these blocks as well.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_cidr_list",
+        serialize_with = "serialize_cidr_list",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub token_bound_cidrs: Option<Vec<String>>,
     /// If set, will encode an explicit max TTL onto the token. This is a hard cap even if token_ttl and
     /// token_max_ttl would otherwise allow a renewal.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub token_explicit_max_ttl: Option<VaultDuration>,
     /// If set, the default policy will not be set on generated tokens; otherwise it will be added to
     /// the policies set in token_policies.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub token_no_default_policy: Option<bool>,
     /// Duration after which the issued token can no longer be renewed.
     pub token_max_ttl: VaultDuration,
@@ -445,16 +1108,44 @@ pub struct AppRoleProperties {
     /// renewed it never expires, but the TTL set on the token at each renewal is fixed to the value
     /// specified here. If this value is modified, the token will pick up the new value at its next
     /// renewal.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub token_period: Option<VaultDuration>,
     /// List of policies to encode onto generated tokens.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub token_policies: Option<Vec<String>>,
     /// The incremental lifetime for generated tokens.
     pub token_ttl: VaultDuration,
     /// The type of token that should be generated. Can be service, batch, or default to use the mount's
     /// tuned default (which unless changed will be service tokens). For token store roles, there are two
     /// additional possibilities: default-service and default-batch which specify the type to return unless
-    /// the client requests a different type at generation time.
-    pub token_type: TokenType,
+    /// the client requests a different type at generation time.  Not
+    /// reported by vault versions before 1.2.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_type: Option<TokenType>,
+}
+
+/// Secret ID generated for an `AppRole`
+#[derive(Deserialize)]
+pub struct SecretIdResponse {
+    /// The secret ID, to log in with
+    pub secret_id: String,
+    /// Accessor of the secret ID, to look it up or destroy it with
+    pub secret_id_accessor: String,
+}
+
+impl fmt::Debug for SecretIdResponse {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SecretIdResponse")
+            .field("secret_id", &REDACTED)
+            .field("secret_id_accessor", &self.secret_id_accessor)
+            .finish()
+    }
+}
+
+/// Role ID of an `AppRole`, used in `VaultResponse`
+#[derive(Deserialize, Debug)]
+struct RoleIdData {
+    role_id: String,
 }
 
 /// Payload to send to vault when authenticating via `AppId`
@@ -471,59 +1162,590 @@ struct AppRolePayload {
     secret_id: Option<String>,
 }
 
-/// Postgresql secret backend
+/// Payload to send to vault when authenticating via `Kubernetes`
 #[derive(Deserialize, Serialize, Debug)]
-pub struct PostgresqlLogin {
-    /// Password
-    pub password: String,
-    /// Username
-    pub username: String,
+struct KubernetesPayload {
+    role: String,
+    jwt: String,
 }
 
-/// Response sent by vault when listing policies.  We hide this from the
-/// caller.
+/// Payload to send to vault when authenticating via `AWS` using the `iam` method
 #[derive(Deserialize, Serialize, Debug)]
-struct PoliciesResponse {
-    policies: Vec<String>,
+struct AwsIamPayload {
+    role: String,
+    iam_http_request_method: String,
+    iam_request_url: String,
+    iam_request_body: String,
+    iam_request_headers: String,
 }
 
-/// Response sent by vault when issuing a `LIST` request.
+/// Payload to send to vault when authenticating via `userpass`
 #[derive(Deserialize, Serialize, Debug)]
-pub struct ListResponse {
-    /// keys will include the items listed
-    pub keys: Vec<String>,
+struct UserpassPayload {
+    password: String,
 }
 
-/// Options that we use when renewing tokens.
+/// Payload to send to vault when authenticating via `GitHub`
 #[derive(Deserialize, Serialize, Debug)]
-struct RenewTokenOptions {
-    /// Token to renew. This can be part of the URL or the body.
+struct GithubPayload {
     token: String,
-    /// The amount of time for which to renew the lease.  May be ignored or
-    /// overriden by vault.
-    increment: Option<u64>,
 }
 
-/// Options that we use when renewing leases.
+/// Payload to send to vault when authenticating via `TLS Certificates`
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
 #[derive(Deserialize, Serialize, Debug)]
-struct RenewLeaseOptions {
-    lease_id: String,
-    /// The amount of time for which to renew the lease.  May be ignored or
-    /// overriden by vault.
-    increment: Option<u64>,
+struct CertPayload {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
 }
 
-/// Options for creating a token.  This is intended to be used as a
-/// "builder"-style interface, where you create a new `TokenOptions`
-/// object, call a bunch of chained methods on it, and then pass the result
-/// to `Client::create_token`.
-///
-/// ```
-/// use hashicorp_vault::client::{TokenOptions, VaultDuration};
+/// Credentials generated by the database secret engine, for any of its
+/// plugins (PostgreSQL, MySQL, MongoDB, MSSQL, ...)
+#[derive(Deserialize, Serialize)]
+pub struct DatabaseCreds {
+    /// Password
+    pub password: String,
+    /// Username
+    pub username: String,
+}
+
+impl fmt::Debug for DatabaseCreds {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DatabaseCreds")
+            .field("password", &REDACTED)
+            .field("username", &self.username)
+            .finish()
+    }
+}
+
+/// Postgresql secret backend
+pub type PostgresqlLogin = DatabaseCreds;
+
+/// Database credentials bundled with their lease, so they can be renewed
+/// before they expire.
+///
+/// ```no_run
+/// # extern crate hashicorp_vault as vault;
+/// # use vault::{client, Client};
+/// use vault::TryInto;
+///
+/// let host = "http://127.0.0.1:8200";
+/// let token = "test12345";
+/// let client = Client::new(host, token).unwrap();
+///
+/// let mut lease: client::DatabaseCredsLease =
+///     client.database_creds(None, "readonly").unwrap().try_into().unwrap();
+/// println!("connect as {}", lease.creds.username);
+/// lease.renew(&client, None).unwrap();
+/// println!("valid until {:?}", lease.expire_time());
+/// ```
+#[derive(Debug)]
+pub struct DatabaseCredsLease {
+    /// The credentials
+    pub creds: DatabaseCreds,
+    /// Id of the lease, to renew or revoke it with
+    pub lease_id: String,
+    /// True if the lease can be renewed
+    pub renewable: bool,
+    /// Duration of the lease, as of its last renewal
+    pub lease_duration: VaultDuration,
+    expire_time: Instant,
+}
+
+impl DatabaseCredsLease {
+    /// When the lease expires, computed from its duration when it was
+    /// issued or last renewed
+    pub fn expire_time(&self) -> Instant {
+        self.expire_time
+    }
+
+    /// Renew the lease, optionally requesting `increment` more time, and
+    /// update its duration and expiry.  See `VaultClient::renew_lease`.
+    pub fn renew<T: DeserializeOwned>(
+        &mut self,
+        client: &VaultClient<T>,
+        increment: Option<VaultDuration>,
+    ) -> Result<()> {
+        let res = client.renew_lease(self.lease_id.as_str(), increment)?;
+        if let Some(duration) = res.lease_duration {
+            self.expire_time = Instant::now() + duration.0;
+            self.lease_duration = duration;
+        }
+        if let Some(renewable) = res.renewable {
+            self.renewable = renewable;
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<VaultResponse<DatabaseCreds>> for DatabaseCredsLease {
+    type Err = Error;
+    fn try_from(res: VaultResponse<DatabaseCreds>) -> Result<Self> {
+        match (res.data, res.lease_id, res.lease_duration) {
+            (Some(creds), Some(lease_id), Some(lease_duration)) => Ok(DatabaseCredsLease {
+                creds,
+                expire_time: Instant::now() + lease_duration.0,
+                lease_id,
+                renewable: res.renewable.unwrap_or(false),
+                lease_duration,
+            }),
+            _ => Err(Error::Vault(format!(
+                "No credentials or lease found in response to request `{}`",
+                res.request_id.unwrap_or_default()
+            ))),
+        }
+    }
+}
+
+/// Nomad secret backend
+#[derive(Deserialize, Serialize)]
+pub struct NomadCreds {
+    /// Nomad ACL token
+    pub secret_id: String,
+    /// Accessor of the Nomad ACL token
+    pub accessor_id: String,
+}
+
+impl fmt::Debug for NomadCreds {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NomadCreds")
+            .field("secret_id", &REDACTED)
+            .field("accessor_id", &self.accessor_id)
+            .finish()
+    }
+}
+
+/// AWS secret backend
+#[derive(Deserialize, Serialize)]
+pub struct AwsCreds {
+    /// AWS access key id
+    pub access_key: String,
+    /// AWS secret access key
+    pub secret_key: String,
+    /// AWS session token, for STS credentials
+    pub security_token: Option<String>,
+}
+
+impl fmt::Debug for AwsCreds {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AwsCreds")
+            .field("access_key", &self.access_key)
+            .field("secret_key", &REDACTED)
+            .field(
+                "security_token",
+                &self.security_token.as_ref().map(|_| REDACTED),
+            )
+            .finish()
+    }
+}
+
+/// Kind of credentials to generate from the AWS secret backend
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AwsCredsType {
+    /// Credentials of an IAM user, or for an `assumed_role` or
+    /// `federation_token` role, read from `creds/{role}`
+    Iam,
+    /// STS credentials, read from `sts/{role}`
+    Sts,
+}
+
+/// Response sent by vault when listing policies.  We hide this from the
+/// caller.
+#[derive(Deserialize, Serialize, Debug)]
+struct PoliciesResponse {
+    policies: Vec<String>,
+}
+
+/// Response sent by vault when reading a policy from the legacy
+/// `sys/policy` endpoint.  We hide this from the caller.
+#[derive(Deserialize, Serialize, Debug)]
+struct PolicyResponse {
+    rules: String,
+}
+
+/// ACL policy data, used in `VaultResponse`
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AclPolicyData {
+    /// Rules of the policy, in HCL or JSON
+    pub policy: String,
+}
+
+/// Payload to send to vault when writing a policy
+#[derive(Serialize, Debug)]
+struct PolicyPayload<'a> {
+    policy: &'a str,
+}
+
+/// Capabilities data, used in `VaultResponse`
+#[derive(Deserialize, Serialize, Debug)]
+pub struct CapabilitiesData {
+    /// Capabilities on the path, e.g. `read` or `deny`
+    pub capabilities: Vec<String>,
+}
+
+/// Payload to send to vault when looking up capabilities
+#[derive(Serialize, Debug)]
+struct CapabilitiesPayload<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    token: Option<&'a str>,
+    path: &'a str,
+}
+
+/// Error response sent by vault when a request fails.  We hide this from
+/// the caller.
+#[derive(Deserialize, Debug)]
+struct ErrorResponse {
+    errors: Vec<String>,
+}
+
+/// Response sent by vault when issuing a `LIST` request.
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ListResponse {
+    /// keys will include the items listed
+    pub keys: Vec<String>,
+}
+
+/// Host information, returned from `sys/host-info`
+#[derive(Deserialize, Debug)]
+pub struct HostInfo {
+    /// Per-CPU information, as reported by the host
+    pub cpu: Option<Vec<serde_json::Value>>,
+    /// Per-CPU times, as reported by the host
+    pub cpu_times: Option<Vec<serde_json::Value>>,
+    /// Disk usage for each partition
+    pub disk: Option<Vec<HostDisk>>,
+    /// General host details
+    pub host: Option<HostDetails>,
+    /// Virtual memory usage
+    pub memory: Option<HostMemory>,
+    /// Time at which the information was collected
+    pub timestamp: VaultDateTime,
+}
+
+/// Disk usage for a single partition, used in `HostInfo`
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct HostDisk {
+    /// Mount path of the partition
+    pub path: String,
+    /// Filesystem type
+    pub fstype: String,
+    /// Total size in bytes
+    pub total: u64,
+    /// Free space in bytes
+    pub free: u64,
+    /// Used space in bytes
+    pub used: u64,
+    /// Used space as a percentage
+    pub used_percent: f64,
+}
+
+/// General host details, used in `HostInfo`
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct HostDetails {
+    /// Hostname
+    pub hostname: String,
+    /// Uptime in seconds
+    pub uptime: u64,
+    /// Boot time in seconds since unix epoch
+    pub boot_time: u64,
+    /// Number of running processes
+    pub procs: u64,
+    /// Operating system, e.g. `linux`
+    pub os: String,
+    /// Platform, e.g. `ubuntu`
+    pub platform: String,
+    /// Platform version
+    pub platform_version: String,
+    /// Kernel version
+    pub kernel_version: String,
+}
+
+/// Virtual memory usage, used in `HostInfo`
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct HostMemory {
+    /// Total memory in bytes
+    pub total: u64,
+    /// Available memory in bytes
+    pub available: u64,
+    /// Used memory in bytes
+    pub used: u64,
+    /// Used memory as a percentage
+    pub used_percent: f64,
+    /// Free memory in bytes
+    pub free: u64,
+}
+
+/// Health of a vault server, returned from `sys/health`
+#[derive(Deserialize, Debug)]
+pub struct HealthStatus {
+    /// Whether vault has been initialized
+    pub initialized: bool,
+    /// Whether vault is sealed
+    pub sealed: bool,
+    /// Whether this server is a standby node
+    pub standby: bool,
+    /// Vault version
+    pub version: String,
+    /// Cluster name, only reported once vault is initialized and unsealed
+    pub cluster_name: Option<String>,
+}
+
+/// High availability status of a vault server, returned from `sys/leader`
+#[derive(Deserialize, Debug)]
+pub struct LeaderStatus {
+    /// Whether high availability is enabled
+    pub ha_enabled: bool,
+    /// Whether the server answering is the active node
+    pub is_self: bool,
+    /// API address of the active node
+    pub leader_address: String,
+    /// Cluster address of the active node
+    pub leader_cluster_address: String,
+}
+
+/// Seal status of a vault server, returned from `sys/seal-status` and
+/// `sys/unseal`
+#[derive(Deserialize, Debug)]
+pub struct SealStatus {
+    /// Whether vault is sealed
+    pub sealed: bool,
+    /// Number of unseal keys required to unseal vault
+    pub t: u64,
+    /// Total number of unseal key shares
+    pub n: u64,
+    /// Number of unseal keys provided so far
+    pub progress: u64,
+    /// Vault version
+    pub version: String,
+}
+
+/// Payload to send to vault when providing an unseal key
+#[derive(Serialize, Debug)]
+struct UnsealPayload<'a> {
+    key: &'a str,
+}
+
+/// Replication status, returned from `sys/replication/status`
+#[derive(Deserialize, Debug)]
+pub struct ReplicationStatus {
+    /// Disaster recovery replication status
+    pub dr: ReplicationModeStatus,
+    /// Performance replication status
+    pub performance: ReplicationModeStatus,
+}
+
+/// Status of a single replication type, used in `ReplicationStatus`
+#[derive(Deserialize, Debug)]
+pub struct ReplicationModeStatus {
+    /// Replication mode, e.g. `primary`, `secondary` or `disabled`
+    pub mode: String,
+    /// Replication cluster id
+    pub cluster_id: Option<String>,
+    /// Replication state, e.g. `running` or `stream-wals`
+    pub state: Option<String>,
+    /// Last write-ahead log index written locally
+    pub last_wal: Option<u64>,
+    /// Last write-ahead log index received from the primary (secondaries only)
+    pub last_remote_wal: Option<u64>,
+    /// Merkle root of the replicated data
+    pub merkle_root: Option<String>,
+    /// Known secondaries (primaries only)
+    pub known_secondaries: Option<Vec<String>>,
+    /// Address of the primary cluster (secondaries only)
+    pub primary_cluster_addr: Option<String>,
+}
+
+/// Status of a root token generation attempt, returned from the
+/// `sys/generate-root` endpoints
+#[derive(Deserialize)]
+pub struct GenerateRootStatus {
+    /// True if a root generation attempt is in progress
+    pub started: bool,
+    /// Nonce of the current attempt, which must be supplied with each key
+    #[serde(default, deserialize_with = "deserialize_optional_string")]
+    pub nonce: Option<String>,
+    /// Number of unseal keys provided so far
+    pub progress: u64,
+    /// Number of unseal keys required to complete the attempt
+    pub required: u64,
+    /// True once enough unseal keys have been provided
+    pub complete: bool,
+    /// Encoded root token, only set once the attempt is complete
+    #[serde(default, deserialize_with = "deserialize_optional_string")]
+    pub encoded_token: Option<String>,
+    /// Encoded root token as returned by older vault versions
+    #[serde(default, deserialize_with = "deserialize_optional_string")]
+    pub encoded_root_token: Option<String>,
+    /// Fingerprint of the PGP key used to encrypt the root token, if any
+    #[serde(default, deserialize_with = "deserialize_optional_string")]
+    pub pgp_fingerprint: Option<String>,
+    /// One-time password used to decode the root token, only returned when
+    /// the attempt is initialized
+    #[serde(default, deserialize_with = "deserialize_optional_string")]
+    pub otp: Option<String>,
+    /// Length of the one-time password
+    #[serde(default)]
+    pub otp_length: u64,
+}
+
+impl fmt::Debug for GenerateRootStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GenerateRootStatus")
+            .field("started", &self.started)
+            .field("nonce", &self.nonce)
+            .field("progress", &self.progress)
+            .field("required", &self.required)
+            .field("complete", &self.complete)
+            .field(
+                "encoded_token",
+                &self.encoded_token.as_ref().map(|_| REDACTED),
+            )
+            .field(
+                "encoded_root_token",
+                &self.encoded_root_token.as_ref().map(|_| REDACTED),
+            )
+            .field("pgp_fingerprint", &self.pgp_fingerprint)
+            .field("otp", &self.otp.as_ref().map(|_| REDACTED))
+            .field("otp_length", &self.otp_length)
+            .finish()
+    }
+}
+
+impl GenerateRootStatus {
+    /// The state of the root generation attempt
+    pub fn state(&self) -> GenerateRootState {
+        if self.complete {
+            let encoded_token = self
+                .encoded_token
+                .as_ref()
+                .or(self.encoded_root_token.as_ref())
+                .cloned()
+                .unwrap_or_default();
+            GenerateRootState::Complete { encoded_token }
+        } else if self.started {
+            GenerateRootState::InProgress {
+                nonce: self.nonce.clone().unwrap_or_default(),
+                progress: self.progress,
+                required: self.required,
+            }
+        } else {
+            GenerateRootState::NotStarted
+        }
+    }
+}
+
+/// State of a root token generation attempt
+#[derive(Debug, PartialEq, Eq)]
+pub enum GenerateRootState {
+    /// No attempt is in progress
+    NotStarted,
+    /// An attempt is in progress and waiting for more unseal keys
+    InProgress {
+        /// Nonce to supply with each unseal key
+        nonce: String,
+        /// Number of unseal keys provided so far
+        progress: u64,
+        /// Number of unseal keys required
+        required: u64,
+    },
+    /// The attempt is complete
+    Complete {
+        /// Root token, encoded with the one-time password or PGP key
+        encoded_token: String,
+    },
+}
+
+/// Payload to send to vault when starting a root generation attempt
+#[derive(Serialize, Debug)]
+struct GenerateRootInitPayload<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pgp_key: Option<&'a str>,
+}
+
+/// Payload to send to vault when providing an unseal key
+#[derive(Serialize, Debug)]
+struct GenerateRootUpdatePayload<'a> {
+    key: &'a str,
+    nonce: &'a str,
+}
+
+/// Options that we use when renewing tokens.
+#[derive(Deserialize, Serialize, Debug)]
+struct RenewTokenOptions {
+    /// Token to renew. This can be part of the URL or the body.
+    token: String,
+    /// The amount of time for which to renew the lease.  May be ignored or
+    /// overriden by vault.
+    increment: Option<VaultDuration>,
+}
+
+/// Options that we use when looking up or revoking a specific token.
+#[derive(Deserialize, Serialize, Debug)]
+struct TokenIdOptions {
+    token: String,
+}
+
+/// Options that we use when operating on tokens by accessor.
+#[derive(Serialize, Debug)]
+struct AccessorOptions<'a> {
+    accessor: &'a str,
+}
+
+/// Options that we use when looking up leases.
+#[derive(Deserialize, Serialize, Debug)]
+struct LookupLeaseOptions {
+    lease_id: String,
+}
+
+/// Payload to send to vault when revoking a lease
+#[derive(Serialize, Debug)]
+struct RevokeLeasePayload<'a> {
+    lease_id: &'a str,
+}
+
+/// Lease information, returned from `sys/leases/lookup`
+#[derive(Deserialize, Debug)]
+pub struct LeaseInfo {
+    /// Lease id
+    pub id: String,
+    /// Time the lease was issued
+    pub issue_time: VaultDateTime,
+    /// Time the lease expires, if it does
+    #[serde(default, deserialize_with = "deserialize_optional_date_time")]
+    pub expire_time: Option<VaultDateTime>,
+    /// Time the lease was last renewed, if it has been
+    #[serde(default, deserialize_with = "deserialize_optional_date_time")]
+    pub last_renewal: Option<VaultDateTime>,
+    /// Whether the lease can be renewed
+    pub renewable: bool,
+    /// Time remaining before the lease expires
+    pub ttl: VaultDuration,
+}
+
+/// Options that we use when renewing leases.
+#[derive(Deserialize, Serialize, Debug)]
+struct RenewLeaseOptions {
+    lease_id: String,
+    /// The amount of time for which to renew the lease.  May be ignored or
+    /// overriden by vault.
+    increment: Option<VaultDuration>,
+}
+
+/// Options for creating a token.  This is intended to be used as a
+/// "builder"-style interface, where you create a new `TokenOptions`
+/// object, call a bunch of chained methods on it, and then pass the result
+/// to `Client::create_token`.
+///
+/// ```
+/// use hashicorp_vault::client::{TokenOptions, VaultDuration};
 ///
 /// let _ = TokenOptions::default()
 ///   .id("test12345")
 ///   .policies(vec!("root"))
+///   .meta(vec!(("service", "billing")))
 ///   .default_policy(false)
 ///   .orphan(true)
 ///   .renewable(false)
@@ -541,7 +1763,7 @@ struct RenewLeaseOptions {
 pub struct TokenOptions {
     id: Option<String>,
     policies: Option<Vec<String>>,
-    // TODO: `meta`
+    meta: Option<HashMap<String, String>>,
     no_parent: Option<bool>,
     no_default_policy: Option<bool>,
     renewable: Option<bool>,
@@ -573,6 +1795,23 @@ impl TokenOptions {
         self
     }
 
+    /// Attach metadata to the created token.  This is returned when looking
+    /// up the token and included in audit logs, which makes it useful for
+    /// recording who requested the token.
+    pub fn meta<I, K, V>(mut self, meta: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.meta = Some(
+            meta.into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect(),
+        );
+        self
+    }
+
     /// Should we grant access to the `default` policy?  Defaults to true.
     pub fn default_policy(mut self, enable: bool) -> Self {
         self.no_default_policy = Some(!enable);
@@ -621,211 +1860,4107 @@ impl TokenOptions {
     }
 }
 
-/// http verbs
-#[derive(Debug)]
-pub enum HttpVerb {
-    /// GET
-    GET,
-    /// POST
-    POST,
-    /// PUT
-    PUT,
-    /// DELETE
-    DELETE,
-    /// LIST
-    LIST,
+/// Options for mounting a secret engine.
+///
+/// If an option is not specified, vault's defaults for the engine are used.
+#[derive(Default, Debug)]
+pub struct MountConfig {
+    description: Option<String>,
+    default_lease_ttl: Option<String>,
+    max_lease_ttl: Option<String>,
+    options: Option<HashMap<String, String>>,
 }
 
-#[derive(Debug, Serialize)]
-struct SecretContainer<T: Serialize> {
-    data: T,
+impl MountConfig {
+    /// A human-friendly description of the mount.
+    pub fn description<S: Into<String>>(mut self, description: S) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// The default lease duration for secrets from this mount, overriding
+    /// the system default.
+    pub fn default_lease_ttl<D: Into<VaultDuration>>(mut self, ttl: D) -> Self {
+        self.default_lease_ttl = Some(format!("{}s", ttl.into().0.as_secs()));
+        self
+    }
+
+    /// The maximum lease duration for secrets from this mount, overriding
+    /// the system default.
+    pub fn max_lease_ttl<D: Into<VaultDuration>>(mut self, ttl: D) -> Self {
+        self.max_lease_ttl = Some(format!("{}s", ttl.into().0.as_secs()));
+        self
+    }
+
+    /// Engine-specific options, e.g. `version` = `2` for the `kv` engine.
+    pub fn options<I, K, V>(mut self, options: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.options = Some(
+            options
+                .into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect(),
+        );
+        self
+    }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
-struct DefaultSecretType<T: AsRef<str>> {
-    value: T,
+/// Payload to send to vault when mounting a secret engine
+#[derive(Serialize, Debug)]
+struct MountPayload<'a> {
+    #[serde(rename = "type")]
+    engine_type: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a String>,
+    config: MountTtlPayload<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    options: Option<&'a HashMap<String, String>>,
 }
 
-/// endpoint response variants
-#[derive(Debug)]
-pub enum EndpointResponse<D> {
-    /// Vault response
-    VaultResponse(VaultResponse<D>),
-    /// Empty, but still successful response
-    Empty,
+/// TTL overrides sent to vault when mounting a secret engine
+#[derive(Serialize, Debug)]
+struct MountTtlPayload<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_lease_ttl: Option<&'a String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_lease_ttl: Option<&'a String>,
 }
 
-impl VaultClient<TokenData> {
-    /// Construct a `VaultClient` from an existing vault token
-    pub fn new<U, T: Into<String>>(host: U, token: T) -> Result<VaultClient<TokenData>>
-    where
-        U: TryInto<Url, Err = Error>,
-    {
-        let host = host.try_into()?;
-        let client = Client::new();
-        let token = token.into();
-        let res = handle_reqwest_response(
-            client
-                .get(host.join("/v1/auth/token/lookup-self")?)
-                .header("X-Vault-Token", token.clone())
-                .send(),
-        )?;
-        let decoded: VaultResponse<TokenData> = parse_vault_response(res)?;
-        Ok(VaultClient {
-            host,
-            token,
-            client,
-            data: Some(decoded),
-            secret_backend: "secret".into(),
-        })
+/// A mounted secret engine, returned from `sys/mounts`
+#[derive(Deserialize, Debug)]
+pub struct MountInfo {
+    /// Type of the secret engine, e.g. `kv` or `transit`
+    #[serde(rename = "type")]
+    pub mount_type: String,
+    /// Description of the mount
+    pub description: String,
+    /// Mount configuration
+    pub config: MountConfigInfo,
+}
+
+/// Configuration of a mounted secret engine, used in `MountInfo`
+#[derive(Deserialize, Debug)]
+pub struct MountConfigInfo {
+    /// Default lease duration; `0` means the system default is used
+    pub default_lease_ttl: VaultDuration,
+    /// Maximum lease duration; `0` means the system default is used
+    pub max_lease_ttl: VaultDuration,
+}
+
+/// Settings to change when tuning a mounted secret engine.
+///
+/// Settings which are not specified are left unchanged.
+#[derive(Default, Serialize, Debug)]
+pub struct MountTuneConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_lease_ttl: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_lease_ttl: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    audit_non_hmac_request_keys: Option<Vec<String>>,
+}
+
+impl MountTuneConfig {
+    /// The default lease duration for secrets from this mount.
+    pub fn default_lease_ttl<D: Into<VaultDuration>>(mut self, ttl: D) -> Self {
+        self.default_lease_ttl = Some(format!("{}s", ttl.into().0.as_secs()));
+        self
     }
-    /// Construct a `VaultClient` from an existing vault token and reqwest::Client
-    pub fn new_from_reqwest<U, T: Into<String>>(
-        host: U,
-        token: T,
-        cli: Client,
-    ) -> Result<VaultClient<TokenData>>
+
+    /// The maximum lease duration for secrets from this mount.
+    pub fn max_lease_ttl<D: Into<VaultDuration>>(mut self, ttl: D) -> Self {
+        self.max_lease_ttl = Some(format!("{}s", ttl.into().0.as_secs()));
+        self
+    }
+
+    /// Keys of request data which audit devices should log in plaintext
+    /// rather than HMAC.
+    pub fn audit_non_hmac_request_keys<I>(mut self, keys: I) -> Self
     where
-        U: TryInto<Url, Err = Error>,
+        I: IntoIterator,
+        I::Item: Into<String>,
     {
-        let host = host.try_into()?;
-        let client = cli;
-        let token = token.into();
-        let res = handle_reqwest_response(
-            client
-                .get(host.join("/v1/auth/token/lookup-self")?)
-                .header("X-Vault-Token", token.clone())
-                .send(),
-        )?;
-        let decoded: VaultResponse<TokenData> = parse_vault_response(res)?;
-        Ok(VaultClient {
+        self.audit_non_hmac_request_keys = Some(keys.into_iter().map(|k| k.into()).collect());
+        self
+    }
+}
+
+/// Tuned settings of a mounted secret engine, returned from
+/// `sys/mounts/:path/tune`
+#[derive(Deserialize, Debug)]
+pub struct MountTuneInfo {
+    /// Default lease duration for secrets from this mount
+    pub default_lease_ttl: VaultDuration,
+    /// Maximum lease duration for secrets from this mount
+    pub max_lease_ttl: VaultDuration,
+    /// Description of the mount
+    pub description: Option<String>,
+    /// Keys of request data which audit devices log in plaintext
+    pub audit_non_hmac_request_keys: Option<Vec<String>>,
+}
+
+/// Payload to send to vault when enabling an auth method
+#[derive(Serialize, Debug)]
+struct EnableAuthPayload<'a> {
+    #[serde(rename = "type")]
+    method_type: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+}
+
+/// An enabled auth method, returned from `sys/auth`
+#[derive(Deserialize, Debug)]
+pub struct AuthMethodInfo {
+    /// Type of the auth method, e.g. `approle` or `kubernetes`
+    #[serde(rename = "type")]
+    pub method_type: String,
+    /// Accessor of the auth method, used to identify it in entity aliases
+    pub accessor: String,
+}
+
+/// Payload to send to vault when enabling an audit device
+#[derive(Serialize, Debug)]
+struct EnableAuditPayload<'a> {
+    #[serde(rename = "type")]
+    device_type: &'a str,
+    options: &'a HashMap<String, String>,
+}
+
+/// An enabled audit device, returned from `sys/audit`
+#[derive(Deserialize, Debug)]
+pub struct AuditDeviceInfo {
+    /// Type of the audit device, e.g. `file` or `syslog`
+    #[serde(rename = "type")]
+    pub device_type: String,
+    /// Human-friendly description of the audit device
+    pub description: String,
+    /// Device specific options, e.g. `file_path` for a `file` device
+    #[serde(default)]
+    pub options: HashMap<String, String>,
+}
+
+/// Options for issuing a certificate from the PKI secret engine.
+///
+/// If an option is not specified, the defaults of the PKI role are used.
+#[derive(Default, Serialize, Debug)]
+pub struct PkiIssueOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    alt_names: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ttl: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    format: Option<String>,
+}
+
+impl PkiIssueOptions {
+    /// Subject alternative names (DNS names or email addresses) to include
+    /// in the certificate.
+    pub fn alt_names<I>(mut self, alt_names: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let alt_names: Vec<String> = alt_names.into_iter().map(|n| n.into()).collect();
+        self.alt_names = Some(alt_names.join(","));
+        self
+    }
+
+    /// How long should the certificate remain valid for?
+    pub fn ttl<D: Into<VaultDuration>>(mut self, ttl: D) -> Self {
+        self.ttl = Some(format!("{}s", ttl.into().0.as_secs()));
+        self
+    }
+
+    /// Encoding of the returned certificate and key: `pem` (the default),
+    /// `der` or `pem_bundle`.
+    pub fn format<S: Into<String>>(mut self, format: S) -> Self {
+        self.format = Some(format.into());
+        self
+    }
+}
+
+/// Payload to send to vault when issuing a certificate
+#[derive(Serialize, Debug)]
+struct PkiIssuePayload<'a> {
+    common_name: &'a str,
+    #[serde(flatten)]
+    opts: &'a PkiIssueOptions,
+}
+
+/// Payload to send to vault when revoking a certificate
+#[derive(Serialize, Debug)]
+struct PkiRevokePayload {
+    serial_number: String,
+}
+
+/// Certificate revocation, used in `VaultResponse`
+#[derive(Deserialize, Debug)]
+struct PkiRevocationData {
+    revocation_time: VaultNaiveDateTime,
+}
+
+/// A certificate issued by the PKI secret engine
+#[derive(Deserialize)]
+pub struct IssuedCert {
+    /// The issued certificate
+    pub certificate: String,
+    /// Certificate of the issuing CA
+    pub issuing_ca: String,
+    /// Chain of CA certificates, from the issuing CA up
+    pub ca_chain: Option<Vec<String>>,
+    /// Private key of the issued certificate
+    pub private_key: String,
+    /// Serial number of the issued certificate
+    pub serial_number: String,
+}
+
+impl fmt::Debug for IssuedCert {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("IssuedCert")
+            .field("certificate", &self.certificate)
+            .field("issuing_ca", &self.issuing_ca)
+            .field("ca_chain", &self.ca_chain)
+            .field("private_key", &REDACTED)
+            .field("serial_number", &self.serial_number)
+            .finish()
+    }
+}
+
+/// TOTP code, used in `VaultResponse`
+#[derive(Deserialize, Debug)]
+struct TotpCodeData {
+    code: String,
+}
+
+/// Payload to send to vault when validating a TOTP code
+#[derive(Serialize, Debug)]
+struct TotpValidatePayload<'a> {
+    code: &'a str,
+}
+
+/// TOTP code validation, used in `VaultResponse`
+#[derive(Deserialize, Debug)]
+struct TotpValidateData {
+    valid: bool,
+}
+
+/// Payload to send to vault when generating an SSH one-time password
+#[derive(Serialize, Debug)]
+struct SshOtpPayload<'a> {
+    ip: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    username: Option<&'a str>,
+}
+
+/// An SSH one-time password generated by the SSH secret engine
+#[derive(Deserialize)]
+pub struct SshOtp {
+    /// The one-time password
+    pub key: String,
+    /// Type of the key, always `otp`
+    pub key_type: String,
+    /// SSH port of the host
+    pub port: u16,
+    /// User the password is valid for
+    pub username: String,
+    /// IP address of the host
+    pub ip: String,
+}
+
+impl fmt::Debug for SshOtp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SshOtp")
+            .field("key", &REDACTED)
+            .field("key_type", &self.key_type)
+            .field("port", &self.port)
+            .field("username", &self.username)
+            .field("ip", &self.ip)
+            .finish()
+    }
+}
+
+/// Options for signing an SSH public key with the SSH secret engine.
+///
+/// If an option is not specified, the defaults of the SSH role are used.
+#[derive(Default, Serialize, Debug)]
+pub struct SshSignOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    valid_principals: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ttl: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cert_type: Option<String>,
+}
+
+impl SshSignOptions {
+    /// Usernames or hostnames the certificate is valid for.
+    pub fn valid_principals<I>(mut self, principals: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let principals: Vec<String> = principals.into_iter().map(|p| p.into()).collect();
+        self.valid_principals = Some(principals.join(","));
+        self
+    }
+
+    /// How long should the certificate remain valid for?
+    pub fn ttl<D: Into<VaultDuration>>(mut self, ttl: D) -> Self {
+        self.ttl = Some(format!("{}s", ttl.into().0.as_secs()));
+        self
+    }
+
+    /// Type of certificate to issue: `user` (the default) or `host`.
+    pub fn cert_type<S: Into<String>>(mut self, cert_type: S) -> Self {
+        self.cert_type = Some(cert_type.into());
+        self
+    }
+}
+
+/// Payload to send to vault when signing an SSH public key
+#[derive(Serialize, Debug)]
+struct SshSignPayload<'a> {
+    public_key: &'a str,
+    #[serde(flatten)]
+    opts: &'a SshSignOptions,
+}
+
+/// An SSH public key signed by the SSH secret engine
+#[derive(Deserialize, Debug)]
+pub struct SignedSshKey {
+    /// Serial number of the certificate
+    pub serial_number: String,
+    /// The signed certificate, in OpenSSH format
+    pub signed_key: String,
+}
+
+/// An identity entity, the stable identity of a client across auth methods
+#[derive(Deserialize, Debug)]
+pub struct EntityInfo {
+    /// Id of the entity
+    pub id: String,
+    /// Name of the entity
+    pub name: String,
+    /// Policies attached to the entity, granted to all of its tokens
+    pub policies: Option<Vec<String>>,
+    /// Aliases of the entity, one per auth method it has logged in with
+    pub aliases: Vec<EntityAlias>,
+    /// Metadata attached to the entity
+    pub metadata: Option<HashMap<String, String>>,
+    /// True if tokens of the entity are denied access
+    pub disabled: bool,
+}
+
+/// An alias of an identity entity, tying it to a user of an auth method
+#[derive(Deserialize, Debug)]
+pub struct EntityAlias {
+    /// Id of the alias
+    pub id: String,
+    /// Name of the user in the auth method, e.g. a userpass username
+    pub name: String,
+    /// Accessor of the auth method, see `AuthMethodInfo`
+    pub mount_accessor: String,
+    /// Path the auth method is mounted at
+    pub mount_path: String,
+    /// Type of the auth method
+    pub mount_type: String,
+}
+
+/// http verbs
+#[derive(Debug)]
+pub enum HttpVerb {
+    /// GET
+    GET,
+    /// POST
+    POST,
+    /// PUT
+    PUT,
+    /// DELETE
+    DELETE,
+    /// LIST
+    LIST,
+}
+
+/// Payload to send to vault when configuring KV v2 secret metadata
+#[derive(Serialize, Debug)]
+struct KvV2MetadataPayload {
+    max_versions: u64,
+    cas_required: bool,
+}
+
+/// Payload to send to vault when deleting, undeleting or destroying KV v2
+/// secret versions
+#[derive(Serialize, Debug)]
+struct KvV2VersionsPayload<'a> {
+    versions: &'a [u64],
+}
+
+#[derive(Debug, Serialize)]
+struct SecretContainer<T: Serialize> {
+    data: T,
+}
+
+#[derive(Debug, Serialize)]
+struct CasSecretContainer<'a, T: Serialize> {
+    options: CasOptions,
+    data: &'a T,
+}
+
+#[derive(Debug, Serialize)]
+struct CasOptions {
+    cas: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct DefaultSecretType<T: AsRef<str>> {
+    value: T,
+}
+
+/// endpoint response variants
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug)]
+pub enum EndpointResponse<D> {
+    /// Vault response
+    VaultResponse(VaultResponse<D>),
+    /// Empty, but still successful response
+    Empty,
+}
+
+impl VaultClient<TokenData> {
+    /// Construct a `VaultClient` from an existing vault token.  `host` may
+    /// be a `&str`, a `String` or an already parsed `Url`.
+    pub fn new<U, T: Into<String>>(host: U, token: T) -> Result<VaultClient<TokenData>>
+    where
+        U: TryInto<Url, Err = Error>,
+    {
+        VaultClient::new_from_reqwest(host, token, http_client_builder().build()?)
+    }
+
+    /// Construct a `VaultClient` from an existing vault token and reqwest::Client
+    ///
+    /// Use this to configure timeouts, proxies or additional root
+    /// certificates (e.g. for a self-signed Vault) on the underlying client.
+    /// Disable redirects on it with `redirect(Policy::none())` to have
+    /// standby redirects followed as described on `Error::Standby`;
+    /// otherwise reqwest follows them itself.
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # extern crate reqwest;
+    /// # use vault::Client;
+    /// use std::time::Duration;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let http = reqwest::blocking::Client::builder()
+    ///     .timeout(Duration::from_secs(10))
+    ///     .build()
+    ///     .unwrap();
+    /// let client = Client::new_from_reqwest(host, token, http).unwrap();
+    /// ```
+    pub fn new_from_reqwest<U, T: Into<String>>(
+        host: U,
+        token: T,
+        cli: Client,
+    ) -> Result<VaultClient<TokenData>>
+    where
+        U: TryInto<Url, Err = Error>,
+    {
+        VaultClient::connect(
+            host.try_into()?,
+            token.into(),
+            cli,
+            None,
+            false,
+            DEFAULT_API_PREFIX.into(),
+        )
+    }
+
+    fn connect(
+        host: Url,
+        token: String,
+        client: Client,
+        namespace: Option<String>,
+        request_header: bool,
+        api_prefix: String,
+    ) -> Result<VaultClient<TokenData>> {
+        let host = normalize_host(host)?;
+        let mut req = client
+            .get(endpoint_url(
+                &host,
+                &api_prefix,
+                "/v1/auth/token/lookup-self",
+            )?)
+            .header("X-Vault-Token", token.clone());
+        if let Some(ref namespace) = namespace {
+            req = req.header("X-Vault-Namespace", namespace.as_str());
+        }
+        if request_header {
+            req = req.header("X-Vault-Request", "true");
+        }
+        let res = handle_reqwest_response(req.send())?;
+        let decoded: VaultResponse<TokenData> = parse_vault_response(res)?;
+        Ok(VaultClient {
             host,
             token,
             client,
             data: Some(decoded),
             secret_backend: "secret".into(),
+            strict_renewal: false,
+            namespace,
+            request_header,
+            api_prefix,
+            max_retries: 0,
+            retry_backoff: Duration::from_millis(100),
+            timeout: None,
+            secret_cache: None,
         })
     }
-}
+}
+
+impl VaultClient<TokenData> {
+    /// TTL of the token as of its lookup on construction or, once renewed,
+    /// its last renewal.  Reads the cached `data`; no request is made.
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    /// use vault::client::VaultDuration;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// // the root token never expires
+    /// assert_eq!(client.token_ttl(), Some(VaultDuration::seconds(0)));
+    /// assert!(client.token_policies().contains(&"root".to_owned()));
+    /// ```
+    pub fn token_ttl(&self) -> Option<VaultDuration> {
+        let data = self.data.as_ref()?;
+        match data.auth {
+            Some(ref auth) => auth.lease_duration,
+            None => data.data.as_ref().map(|d| d.ttl),
+        }
+    }
+
+    /// Policies attached to the token.  Reads the cached `data`; no request
+    /// is made.
+    pub fn token_policies(&self) -> Vec<String> {
+        match self.data {
+            Some(VaultResponse {
+                auth: Some(ref auth),
+                ..
+            }) => auth.policies.clone(),
+            Some(VaultResponse {
+                data: Some(ref data),
+                ..
+            }) => data.policies.clone(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Whether the token can be renewed, if vault said so.  Reads the cached
+    /// `data`; no request is made.
+    pub fn is_renewable(&self) -> Option<bool> {
+        let data = self.data.as_ref()?;
+        match data.auth {
+            Some(ref auth) => Some(auth.renewable),
+            None => data.data.as_ref().and_then(|d| d.renewable),
+        }
+    }
+
+    /// Renew lease for `VaultClient`'s token and updates the
+    /// `self.data.auth` based upon the response.  Corresponds to
+    /// [`/auth/token/renew-self`][token].  In strict mode, `is_renewable`
+    /// is checked before falling back to looking the token up.
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let mut client = Client::new(host, token).unwrap();
+    ///
+    /// client.renew().unwrap();
+    /// ```
+    ///
+    /// [token]: https://www.vaultproject.io/docs/auth/token.html
+    pub fn renew(&mut self) -> Result<()> {
+        let cached_renewable = self.is_renewable();
+        self.renew_checked(cached_renewable)
+    }
+
+    /// Renew the lease for `VaultClient`'s token, as `renew` does, but only
+    /// if its remaining TTL is below `threshold`.  Returns whether the token
+    /// was renewed.  Tokens which never expire, such as root tokens, are
+    /// never renewed.
+    ///
+    /// The remaining TTL is looked up rather than read from `self.data`,
+    /// whose lease duration is only accurate as of the last renewal, so this
+    /// costs a request each call; it is cheap enough to call on a timer.
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    /// use std::time::Duration;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let mut client = Client::new(host, token).unwrap();
+    ///
+    /// // the root token never expires
+    /// assert!(!client.renew_if_expiring(Duration::from_secs(300)).unwrap());
+    /// ```
+    pub fn renew_if_expiring(&mut self, threshold: Duration) -> Result<bool> {
+        let cached_renewable = self.is_renewable();
+        self.renew_if_expiring_checked(threshold, cached_renewable)
+    }
+}
+
+impl VaultClient<()> {
+    /// TTL of the token as of login or its last renewal.  Reads the cached
+    /// `data`; no request is made.
+    pub fn token_ttl(&self) -> Option<VaultDuration> {
+        self.auth().and_then(|auth| auth.lease_duration)
+    }
+
+    /// Policies attached to the token.  Reads the cached `data`; no request
+    /// is made.
+    pub fn token_policies(&self) -> Vec<String> {
+        self.auth()
+            .map(|auth| auth.policies.clone())
+            .unwrap_or_default()
+    }
+
+    /// Whether the token can be renewed.  Reads the cached `data`; no
+    /// request is made.
+    pub fn is_renewable(&self) -> Option<bool> {
+        self.auth().map(|auth| auth.renewable)
+    }
+
+    /// Renew lease for `VaultClient`'s token and updates the
+    /// `self.data.auth` based upon the response.  Corresponds to
+    /// [`/auth/token/renew-self`][token].  In strict mode, `is_renewable`
+    /// is checked before falling back to looking the token up.
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::client::VaultClient;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let mut client = VaultClient::new_no_lookup(host, token).unwrap();
+    ///
+    /// client.renew().unwrap();
+    /// ```
+    ///
+    /// [token]: https://www.vaultproject.io/docs/auth/token.html
+    pub fn renew(&mut self) -> Result<()> {
+        let cached_renewable = self.is_renewable();
+        self.renew_checked(cached_renewable)
+    }
+
+    /// Renew the lease for `VaultClient`'s token, as `renew` does, but only
+    /// if its remaining TTL is below `threshold`.  Returns whether the token
+    /// was renewed.  See `VaultClient<TokenData>::renew_if_expiring`.
+    pub fn renew_if_expiring(&mut self, threshold: Duration) -> Result<bool> {
+        let cached_renewable = self.is_renewable();
+        self.renew_if_expiring_checked(threshold, cached_renewable)
+    }
+
+    fn auth(&self) -> Option<&Auth> {
+        self.data.as_ref().and_then(|d| d.auth.as_ref())
+    }
+}
+
+impl VaultClient<()> {
+    /// Construct a `VaultClient` via the `App ID`
+    /// [auth backend](https://www.vaultproject.io/docs/auth/app-id.html)
+    ///
+    /// NOTE: This backend is now deprecated by vault.
+    #[deprecated(since = "0.6.1")]
+    pub fn new_app_id<U, S1: Into<String>, S2: Into<String>>(
+        host: U,
+        app_id: S1,
+        user_id: S2,
+    ) -> Result<VaultClient<()>>
+    where
+        U: TryInto<Url, Err = Error>,
+    {
+        let payload = AppIdPayload {
+            app_id: app_id.into(),
+            user_id: user_id.into(),
+        };
+        VaultClient::login(
+            host.try_into()?,
+            http_client_builder().build()?,
+            None,
+            false,
+            DEFAULT_API_PREFIX.into(),
+            "/v1/auth/app-id/login",
+            &payload,
+        )
+    }
+
+    /// Construct a `VaultClient` via the `AppRole`
+    /// [auth backend](https://www.vaultproject.io/docs/auth/approle.html)
+    pub fn new_app_role<U, R, S>(
+        host: U,
+        role_id: R,
+        secret_id: Option<S>,
+    ) -> Result<VaultClient<()>>
+    where
+        U: TryInto<Url, Err = Error>,
+        R: Into<String>,
+        S: Into<String>,
+    {
+        VaultClientBuilder::new(host)?.app_role(role_id, secret_id)
+    }
+
+    /// Construct a `VaultClient` via the `Kubernetes`
+    /// [auth backend](https://www.vaultproject.io/docs/auth/kubernetes), using
+    /// the `jwt` of a Kubernetes service account.  `mountpoint` defaults to
+    /// `kubernetes`.
+    ///
+    /// ```no_run
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let jwt = std::fs::read_to_string("/path/to/service-account/token").unwrap();
+    /// let client = Client::new_kubernetes(host, "my-role", jwt, None).unwrap();
+    /// ```
+    pub fn new_kubernetes<U, R, J>(
+        host: U,
+        role: R,
+        jwt: J,
+        mountpoint: Option<String>,
+    ) -> Result<VaultClient<()>>
+    where
+        U: TryInto<Url, Err = Error>,
+        R: Into<String>,
+        J: Into<String>,
+    {
+        VaultClientBuilder::new(host)?.kubernetes(role, jwt, mountpoint)
+    }
+
+    /// Construct a `VaultClient` via the `Kubernetes`
+    /// [auth backend](https://www.vaultproject.io/docs/auth/kubernetes), using
+    /// the service account token mounted into the running pod at
+    /// `/var/run/secrets/kubernetes.io/serviceaccount/token`.  `mountpoint`
+    /// defaults to `kubernetes`.
+    pub fn new_kubernetes_service_account<U, R>(
+        host: U,
+        role: R,
+        mountpoint: Option<String>,
+    ) -> Result<VaultClient<()>>
+    where
+        U: TryInto<Url, Err = Error>,
+        R: Into<String>,
+    {
+        let jwt = fs::read_to_string(KUBERNETES_SERVICE_ACCOUNT_TOKEN)?;
+        VaultClient::new_kubernetes(host, role, jwt.trim(), mountpoint)
+    }
+
+    /// Construct a `VaultClient` via the `AWS`
+    /// [auth backend](https://www.vaultproject.io/docs/auth/aws) using the
+    /// `iam` method.  `mountpoint` defaults to `aws`.
+    ///
+    /// The remaining arguments are the components of a signed
+    /// `sts:GetCallerIdentity` request: its HTTP method, url, body and
+    /// headers.  These are encoded as vault expects, so should be passed
+    /// as-is.
+    ///
+    /// ```no_run
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    /// use std::collections::HashMap;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// // headers from signing the request with AWS Signature Version 4
+    /// let mut headers = HashMap::new();
+    /// headers.insert("Authorization".to_string(), "AWS4-HMAC-SHA256 ...".to_string());
+    /// let client = Client::new_aws_iam(
+    ///     host,
+    ///     "my-role",
+    ///     "POST",
+    ///     "https://sts.amazonaws.com/",
+    ///     "Action=GetCallerIdentity&Version=2011-06-15",
+    ///     &headers,
+    ///     None,
+    /// )
+    /// .unwrap();
+    /// ```
+    pub fn new_aws_iam<U, R>(
+        host: U,
+        role: R,
+        iam_http_request_method: &str,
+        iam_request_url: &str,
+        iam_request_body: &str,
+        iam_request_headers: &HashMap<String, String>,
+        mountpoint: Option<String>,
+    ) -> Result<VaultClient<()>>
+    where
+        U: TryInto<Url, Err = Error>,
+        R: Into<String>,
+    {
+        let path = mountpoint.unwrap_or_else(|| "aws".to_owned());
+        let payload = AwsIamPayload {
+            role: role.into(),
+            iam_http_request_method: iam_http_request_method.to_owned(),
+            iam_request_url: base64::encode(iam_request_url),
+            iam_request_body: base64::encode(iam_request_body),
+            iam_request_headers: base64::encode(serde_json::to_string(iam_request_headers)?),
+        };
+        VaultClient::login(
+            host.try_into()?,
+            http_client_builder().build()?,
+            None,
+            false,
+            DEFAULT_API_PREFIX.into(),
+            &format!("/v1/auth/{}/login", path),
+            &payload,
+        )
+    }
+
+    /// Construct a `VaultClient` via the `userpass`
+    /// [auth backend](https://www.vaultproject.io/docs/auth/userpass).
+    /// `mountpoint` defaults to `userpass`.
+    ///
+    /// ```no_run
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let client = Client::new_userpass(host, "jdoe", "hunter2", None).unwrap();
+    /// ```
+    pub fn new_userpass<U, S1, S2>(
+        host: U,
+        username: S1,
+        password: S2,
+        mountpoint: Option<String>,
+    ) -> Result<VaultClient<()>>
+    where
+        U: TryInto<Url, Err = Error>,
+        S1: AsRef<str>,
+        S2: Into<String>,
+    {
+        let path = mountpoint.unwrap_or_else(|| "userpass".to_owned());
+        let payload = UserpassPayload {
+            password: password.into(),
+        };
+        VaultClient::login(
+            host.try_into()?,
+            http_client_builder().build()?,
+            None,
+            false,
+            DEFAULT_API_PREFIX.into(),
+            &format!("/v1/auth/{}/login/{}", path, username.as_ref()),
+            &payload,
+        )
+    }
+
+    /// Construct a `VaultClient` via the `GitHub`
+    /// [auth backend](https://www.vaultproject.io/docs/auth/github), using a
+    /// GitHub personal access token.  `mountpoint` defaults to `github`.
+    ///
+    /// The GitHub organization and username are returned as auth metadata.
+    ///
+    /// ```no_run
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let client = Client::new_github(host, "github-personal-access-token", None).unwrap();
+    /// let metadata = client.auth_metadata().unwrap();
+    /// println!("logged in as {} of {}", metadata["username"], metadata["org"]);
+    /// ```
+    pub fn new_github<U, S>(
+        host: U,
+        personal_access_token: S,
+        mountpoint: Option<String>,
+    ) -> Result<VaultClient<()>>
+    where
+        U: TryInto<Url, Err = Error>,
+        S: Into<String>,
+    {
+        let path = mountpoint.unwrap_or_else(|| "github".to_owned());
+        let payload = GithubPayload {
+            token: personal_access_token.into(),
+        };
+        VaultClient::login(
+            host.try_into()?,
+            http_client_builder().build()?,
+            None,
+            false,
+            DEFAULT_API_PREFIX.into(),
+            &format!("/v1/auth/{}/login", path),
+            &payload,
+        )
+    }
+
+    /// Construct a `VaultClient` via the `TLS Certificates`
+    /// [auth backend](https://www.vaultproject.io/docs/auth/cert), presenting
+    /// `identity` as the client certificate.  `name` optionally selects the
+    /// certificate role to authenticate against, and `mountpoint` defaults to
+    /// `cert`.
+    ///
+    /// The returned client keeps presenting `identity` on every request.
+    ///
+    /// With the `native-tls` feature, the identity is loaded from a PKCS#12
+    /// archive:
+    ///
+    /// ```no_run
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    /// # #[cfg(feature = "native-tls")]
+    /// # fn main() {
+    /// let host = "https://127.0.0.1:8200";
+    /// let pkcs12 = std::fs::read("client.p12").unwrap();
+    /// let identity = reqwest::Identity::from_pkcs12_der(&pkcs12, "password").unwrap();
+    /// let client = Client::new_tls_cert(host, identity, Some("web"), None).unwrap();
+    /// # }
+    /// # #[cfg(not(feature = "native-tls"))]
+    /// # fn main() {}
+    /// ```
+    ///
+    /// With the `rustls-tls` feature, from a PEM file holding both the
+    /// certificate and its private key:
+    ///
+    /// ```no_run
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    /// # #[cfg(feature = "rustls-tls")]
+    /// # fn main() {
+    /// let host = "https://127.0.0.1:8200";
+    /// let pem = std::fs::read("client.pem").unwrap();
+    /// let identity = reqwest::Identity::from_pem(&pem).unwrap();
+    /// let client = Client::new_tls_cert(host, identity, Some("web"), None).unwrap();
+    /// # }
+    /// # #[cfg(not(feature = "rustls-tls"))]
+    /// # fn main() {}
+    /// ```
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    pub fn new_tls_cert<U, S>(
+        host: U,
+        identity: reqwest::Identity,
+        name: Option<S>,
+        mountpoint: Option<String>,
+    ) -> Result<VaultClient<()>>
+    where
+        U: TryInto<Url, Err = Error>,
+        S: Into<String>,
+    {
+        let path = mountpoint.unwrap_or_else(|| "cert".to_owned());
+        let client = http_client_builder().identity(identity).build()?;
+        let payload = CertPayload {
+            name: name.map(|n| n.into()),
+        };
+        VaultClient::login(
+            host.try_into()?,
+            client,
+            None,
+            false,
+            DEFAULT_API_PREFIX.into(),
+            &format!("/v1/auth/{}/login", path),
+            &payload,
+        )
+    }
+
+    /// Log in by sending `payload` to the `endpoint` of an auth backend, and
+    /// construct a `VaultClient` from the returned client token.
+    fn login<P: Serialize>(
+        host: Url,
+        client: Client,
+        namespace: Option<String>,
+        request_header: bool,
+        api_prefix: String,
+        endpoint: &str,
+        payload: &P,
+    ) -> Result<VaultClient<()>> {
+        let host = normalize_host(host)?;
+        let payload = serde_json::to_string(payload)?;
+        let mut req = client
+            .post(endpoint_url(&host, &api_prefix, endpoint)?)
+            .body(payload);
+        if let Some(ref namespace) = namespace {
+            req = req.header("X-Vault-Namespace", namespace.as_str());
+        }
+        if request_header {
+            req = req.header("X-Vault-Request", "true");
+        }
+        let res = handle_reqwest_response(req.send())?;
+        let decoded: VaultResponse<()> = parse_vault_response(res)?;
+        let token = match decoded.auth {
+            Some(ref auth) => auth.client_token.clone(),
+            None => {
+                return Err(Error::Vault(format!(
+                    "No client token found in response: `{:?}`",
+                    &decoded.auth
+                )))
+            }
+        };
+        Ok(VaultClient {
+            host,
+            token,
+            client,
+            data: Some(decoded),
+            secret_backend: "secret".into(),
+            strict_renewal: false,
+            namespace,
+            request_header,
+            api_prefix,
+            max_retries: 0,
+            retry_backoff: Duration::from_millis(100),
+            timeout: None,
+            secret_cache: None,
+        })
+    }
+
+    /// Construct a `VaultClient` where no lookup is done through vault since it is assumed that the
+    /// provided token is a single-use token.
+    ///
+    /// A common use case for this method is when a `wrapping_token` has been received and you want
+    /// to query the `sys/wrapping/unwrap` endpoint.
+    pub fn new_no_lookup<U, S: Into<String>>(host: U, token: S) -> Result<VaultClient<()>>
+    where
+        U: TryInto<Url, Err = Error>,
+    {
+        let client = http_client_builder().build()?;
+        let host = normalize_host(host.try_into()?)?;
+        Ok(VaultClient {
+            host,
+            token: token.into(),
+            client,
+            data: None,
+            secret_backend: "secret".into(),
+            strict_renewal: false,
+            namespace: None,
+            request_header: false,
+            api_prefix: DEFAULT_API_PREFIX.into(),
+            max_retries: 0,
+            retry_backoff: Duration::from_millis(100),
+            timeout: None,
+            secret_cache: None,
+        })
+    }
+}
+
+impl<T> VaultClient<T>
+where
+    T: DeserializeOwned,
+{
+    /// Set the backend name to be used by this VaultClient
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let mut client = Client::new(host, token).unwrap();
+    /// client.secret_backend("my_secrets");
+    /// ```
+    pub fn secret_backend<S1: Into<String>>(&mut self, backend_name: S1) {
+        self.secret_backend = backend_name.into();
+    }
+
+    /// Set the Vault Enterprise namespace that requests are sent to, via
+    /// the `X-Vault-Namespace` header.  To authenticate within a namespace,
+    /// set it with `VaultClientBuilder::namespace` instead.  Use
+    /// `clear_namespace` to go back to the root namespace.
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let mut client = Client::new(host, token).unwrap();
+    /// client.set_namespace("team-a");
+    /// ```
+    pub fn set_namespace<S: Into<String>>(&mut self, namespace: S) {
+        self.namespace = Some(namespace.into());
+    }
+
+    /// Stop sending the `X-Vault-Namespace` header, so that requests go to
+    /// the root namespace again.
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let mut client = Client::new(host, token).unwrap();
+    /// client.set_namespace("team-a");
+    /// client.clear_namespace();
+    /// ```
+    pub fn clear_namespace(&mut self) {
+        self.namespace = None;
+    }
+
+    /// Check that a token or lease is renewable before attempting to renew
+    /// it, returning `Error::NotRenewable` instead of a vault error when it
+    /// isn't.  Defaults to `false`.
+    ///
+    /// `renew` uses the auth data cached by this client where available.
+    /// Otherwise, and for `renew_token` and `renew_lease`, this costs an
+    /// additional lookup request per renewal.
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let mut client = Client::new(host, token).unwrap();
+    /// client.strict_renewal(true);
+    /// ```
+    pub fn strict_renewal(&mut self, strict: bool) {
+        self.strict_renewal = strict;
+    }
+
+    /// Send the `X-Vault-Request: true` header with every request, as
+    /// required by Vault Agent and some reverse proxies in front of vault.
+    /// Defaults to `false`.  To send it with the request made to
+    /// authenticate too, set it with `VaultClientBuilder::require_request_header`
+    /// instead.
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let mut client = Client::new(host, token).unwrap();
+    /// client.require_request_header(true);
+    /// ```
+    pub fn require_request_header(&mut self, required: bool) {
+        self.request_header = required;
+    }
+
+    /// Override the `/v1` path prefix of the API, e.g. for a proxy which
+    /// rewrites paths.  An empty prefix sends requests to the host's path
+    /// directly.  To authenticate with the prefix too, set it with
+    /// `VaultClientBuilder::api_prefix` instead.
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let mut client = Client::new(host, token).unwrap();
+    /// client.set_api_prefix("/v1");
+    /// ```
+    pub fn set_api_prefix<S: Into<String>>(&mut self, prefix: S) {
+        self.api_prefix = prefix.into();
+    }
+
+    /// Set the timeout applied to each request to vault, overriding the
+    /// timeout of the underlying `reqwest::Client`.  `None` drops the
+    /// override, so the client's own timeout applies again, which is 30
+    /// seconds unless the client was configured otherwise.  This can't
+    /// remove the timeout altogether; for that, pass a `reqwest::Client`
+    /// built with `timeout(None)` to `VaultClientBuilder::client`.
+    ///
+    /// The rest of the client's configuration, e.g. root certificates or
+    /// the identity of `new_tls_cert`, is kept.  To bound the token lookup
+    /// performed by `new`, set the timeout with `VaultClientBuilder::timeout`
+    /// instead.
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    /// use std::time::Duration;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let mut client = Client::new(host, token).unwrap();
+    /// client.set_timeout(Some(Duration::from_secs(5)));
+    /// client.set_timeout(None);
+    /// ```
+    pub fn set_timeout(&mut self, timeout: Option<Duration>) {
+        self.timeout = timeout;
+    }
+
+    /// Retry `GET` and `LIST` requests which fail with a 5xx status, e.g.
+    /// from a load balancer while vault fails over, up to `max_retries`
+    /// times.  The delay before each retry starts at `base_backoff` and
+    /// doubles every attempt.  Other requests are never retried, as they may
+    /// not be safe to repeat.  Defaults to no retries.
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    /// use std::time::Duration;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let mut client = Client::new(host, token).unwrap();
+    /// client.set_retry(3, Duration::from_millis(200));
+    /// ```
+    pub fn set_retry(&mut self, max_retries: u32, base_backoff: Duration) {
+        self.max_retries = max_retries;
+        self.retry_backoff = base_backoff;
+    }
+
+    /// Enable the in-memory cache used by `get_secret_cached`.  Secrets are
+    /// cached for their lease duration, or `default_ttl` if they have none,
+    /// as is the case for KV v2 secrets.  Re-enabling the cache clears it.
+    pub fn enable_secret_cache(&mut self, default_ttl: Duration) {
+        self.secret_cache = Some(SecretCache {
+            default_ttl,
+            entries: Mutex::new(HashMap::new()),
+        });
+    }
+
+    /// Metadata attached to this client's token when it logged in via an
+    /// auth backend, e.g. the GitHub organization and username.
+    pub fn auth_metadata(&self) -> Option<&HashMap<String, String>> {
+        self.data
+            .as_ref()
+            .and_then(|d| d.auth.as_ref())
+            .and_then(|a| a.metadata.as_ref())
+    }
+
+    /// The `reqwest::Client` used to make requests to vault.  This allows
+    /// wrappers to call endpoints this library does not support while
+    /// reusing the connection pool and any client configuration.
+    pub fn http_client(&self) -> &Client {
+        &self.client
+    }
+
+    /// The token used to authenticate requests to vault
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// assert_eq!(client.token(), "test12345");
+    /// ```
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+    /// Renew the token as `renew` does.  In strict mode, `cached_renewable`,
+    /// the renewable flag cached in `data`, is checked before falling back
+    /// to looking the token up.
+    fn renew_checked(&mut self, cached_renewable: Option<bool>) -> Result<()> {
+        if self.strict_renewal {
+            let renewable = match cached_renewable {
+                Some(renewable) => Some(renewable),
+                None => self.lookup()?.data.and_then(|d| d.renewable),
+            };
+            if renewable == Some(false) {
+                return Err(Error::NotRenewable("token".into()));
+            }
+        }
+        let res = self.post::<_, String>("/v1/auth/token/renew-self", None, None)?;
+        let vault_res: VaultResponse<T> = parse_vault_response(res)?;
+        if let Some(ref mut data) = self.data {
+            data.auth = vault_res.auth;
+        }
+        Ok(())
+    }
+
+    /// Renew the token as `renew_if_expiring` does, see `renew_checked`
+    fn renew_if_expiring_checked(
+        &mut self,
+        threshold: Duration,
+        cached_renewable: Option<bool>,
+    ) -> Result<bool> {
+        let ttl = match self.lookup()?.data {
+            Some(data) => data.ttl.0,
+            None => return Err(Error::Vault("No token data found in lookup".into())),
+        };
+        if ttl == Duration::from_secs(0) || ttl >= threshold {
+            return Ok(false);
+        }
+        self.renew_checked(cached_renewable)?;
+        Ok(true)
+    }
+
+    /// Renew the lease for the specified token.  Requires `root`
+    /// privileges.  Corresponds to [`/auth/token/renew[/token]`][token].
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    /// use vault::client::VaultDuration;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// let token_to_renew = "test12345";
+    /// client.renew_token(token_to_renew, None).unwrap();
+    /// client.renew_token(token_to_renew, Some(VaultDuration::hours(1))).unwrap();
+    /// ```
+    ///
+    /// [token]: https://www.vaultproject.io/docs/auth/token.html
+    pub fn renew_token<S: Into<String>>(
+        &self,
+        token: S,
+        increment: Option<VaultDuration>,
+    ) -> Result<Auth> {
+        let token = token.into();
+        if self.strict_renewal {
+            let body = serde_json::to_string(&TokenIdOptions {
+                token: token.clone(),
+            })?;
+            let res = self.post::<_, String>("/v1/auth/token/lookup", Some(&body), None)?;
+            let vault_res: VaultResponse<TokenData> = parse_vault_response(res)?;
+            if vault_res.data.and_then(|d| d.renewable) == Some(false) {
+                return Err(Error::NotRenewable("token".into()));
+            }
+        }
+        let body = serde_json::to_string(&RenewTokenOptions { token, increment })?;
+        let res = self.post::<_, String>("/v1/auth/token/renew", Some(&body), None)?;
+        let vault_res: VaultResponse<()> = parse_vault_response(res)?;
+        vault_res
+            .auth
+            .ok_or_else(|| Error::Vault("No auth data returned while renewing token".to_owned()))
+    }
+
+    /// Revoke `VaultClient`'s token. This token can no longer be used.
+    /// Corresponds to [`/auth/token/revoke-self`][token].
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::{client, Client};
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// // Create a temporary token, and use it to create a new client.
+    /// let opts = client::TokenOptions::default()
+    ///   .ttl(client::VaultDuration::minutes(5));
+    /// let res = client.create_token(&opts).unwrap();
+    /// let mut new_client = Client::new(host, res.client_token).unwrap();
+    ///
+    /// // Issue and use a bunch of temporary dynamic credentials.
+    ///
+    /// // Revoke all our dynamic credentials with a single command.
+    /// new_client.revoke().unwrap();
+    /// ```
+    ///
+    /// Note that we consume our `self` parameter, so you cannot use the
+    /// client after revoking it.  This delegates to `revoke_self`, which
+    /// doesn't, for clients held behind a reference.
+    ///
+    /// [token]: https://www.vaultproject.io/docs/auth/token.html
+    pub fn revoke(self) -> Result<()> {
+        self.revoke_self()
+    }
+
+    /// Revoke `VaultClient`'s token without consuming the client, e.g. one
+    /// stored in a field of a longer-lived struct.  Every later request
+    /// made by the client will fail, so it should be dropped or replaced.
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::{client, Client};
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// let opts = client::TokenOptions::default()
+    ///   .ttl(client::VaultDuration::minutes(5));
+    /// let res = client.create_token(&opts).unwrap();
+    /// let new_client = Client::new(host, res.client_token).unwrap();
+    ///
+    /// new_client.revoke_self().unwrap();
+    /// assert!(new_client.lookup().is_err());
+    /// ```
+    pub fn revoke_self(&self) -> Result<()> {
+        let _ = self.post::<_, String>("/v1/auth/token/revoke-self", None, None)?;
+        Ok(())
+    }
+
+    /// Revoke `token` along with all of its child tokens.  Corresponds to
+    /// [`/auth/token/revoke`][token].
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::{client, Client};
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// let opts = client::TokenOptions::default()
+    ///   .ttl(client::VaultDuration::minutes(1));
+    /// let res = client.create_token(&opts).unwrap();
+    /// client.revoke_token(res.client_token).unwrap();
+    /// ```
+    ///
+    /// [token]: https://www.vaultproject.io/docs/auth/token.html
+    pub fn revoke_token<S: AsRef<str>>(&self, token: S) -> Result<()> {
+        self.revoke_token_at("/v1/auth/token/revoke", token.as_ref())
+    }
+
+    /// Revoke `token` but not its child tokens, which become orphans and
+    /// keep their leases.  Requires `root` or `sudo` privileges.
+    /// Corresponds to [`/auth/token/revoke-orphan`][token].
+    ///
+    /// [token]: https://www.vaultproject.io/docs/auth/token.html
+    pub fn revoke_token_orphan<S: AsRef<str>>(&self, token: S) -> Result<()> {
+        self.revoke_token_at("/v1/auth/token/revoke-orphan", token.as_ref())
+    }
+
+    fn revoke_token_at(&self, endpoint: &str, token: &str) -> Result<()> {
+        let body = serde_json::to_string(&TokenIdOptions {
+            token: token.to_owned(),
+        })?;
+        let _ = self.post::<_, String>(endpoint, Some(&body), None)?;
+        Ok(())
+    }
+
+    /// List the accessors of all outstanding tokens.  Requires `root` or
+    /// `sudo` privileges.  Corresponds to
+    /// [`/auth/token/accessors`][token].
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// let accessors = client.list_token_accessors().unwrap();
+    /// assert!(!accessors.is_empty());
+    /// ```
+    ///
+    /// [token]: https://www.vaultproject.io/docs/auth/token.html
+    pub fn list_token_accessors(&self) -> Result<Vec<String>> {
+        let res = self.list::<_, String>("/v1/auth/token/accessors", None, None)?;
+        let decoded: VaultResponse<ListResponse> = parse_vault_response(res)?;
+        match decoded.data {
+            Some(data) => Ok(data.keys),
+            _ => Err(Error::Vault(format!(
+                "No accessors found in response: `{:#?}`",
+                decoded
+            ))),
+        }
+    }
+
+    /// Revoke the token with the given `accessor`, along with all of its
+    /// child tokens.  Corresponds to [`/auth/token/revoke-accessor`][token].
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::{client, Client};
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// let opts = client::TokenOptions::default()
+    ///   .ttl(client::VaultDuration::minutes(1));
+    /// let res = client.create_token(&opts).unwrap();
+    /// client.revoke_accessor(res.accessor.unwrap()).unwrap();
+    /// ```
+    ///
+    /// [token]: https://www.vaultproject.io/docs/auth/token.html
+    pub fn revoke_accessor<S: AsRef<str>>(&self, accessor: S) -> Result<()> {
+        let body = serde_json::to_string(&AccessorOptions {
+            accessor: accessor.as_ref(),
+        })?;
+        let _ = self.post::<_, String>("/v1/auth/token/revoke-accessor", Some(&body), None)?;
+        Ok(())
+    }
+
+    /// Renew a specific lease that your token controls.  Corresponds to
+    /// [`/v1/sys/lease`][renew].
+    ///
+    /// ```no_run
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    /// use serde::Deserialize;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// #[derive(Deserialize)]
+    /// struct PacketKey {
+    ///   api_key_token: String,
+    /// }
+    ///
+    /// let res = client.get_secret_engine_creds::<PacketKey>("packet", "1h-read-only-user").unwrap();
+    ///
+    /// client.renew_lease(res.lease_id.unwrap(), None).unwrap();
+    /// ```
+    ///
+    /// [renew]: https://www.vaultproject.io/docs/http/sys-renew.html
+    pub fn renew_lease<S: Into<String>>(
+        &self,
+        lease_id: S,
+        increment: Option<VaultDuration>,
+    ) -> Result<VaultResponse<()>> {
+        let lease_id = lease_id.into();
+        if self.strict_renewal && !self.lookup_lease(&lease_id)?.renewable {
+            return Err(Error::NotRenewable(format!("lease `{}`", lease_id)));
+        }
+        let body = serde_json::to_string(&RenewLeaseOptions {
+            lease_id,
+            increment,
+        })?;
+        let res = self.put::<_, String>("/v1/sys/leases/renew", Some(&body), None)?;
+        let vault_res: VaultResponse<()> = parse_vault_response(res)?;
+        Ok(vault_res)
+    }
+
+    /// Look up a lease, including its remaining TTL.  Corresponds to
+    /// [`/sys/leases/lookup`][lookup].
+    ///
+    /// ```no_run
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// let lease = client.lookup_lease("database/creds/readonly/abcdef").unwrap();
+    /// println!("expires in {:?}", lease.ttl);
+    /// ```
+    ///
+    /// [lookup]: https://www.vaultproject.io/api-docs/system/leases#read-lease
+    pub fn lookup_lease(&self, lease_id: &str) -> Result<LeaseInfo> {
+        let body = serde_json::to_string(&LookupLeaseOptions {
+            lease_id: lease_id.to_owned(),
+        })?;
+        let res = self.put::<_, String>("/v1/sys/leases/lookup", Some(&body), None)?;
+        let decoded: VaultResponse<LeaseInfo> = parse_vault_response(res)?;
+        match decoded.data {
+            Some(data) => Ok(data),
+            _ => Err(Error::Vault(format!(
+                "No lease found in response: `{:#?}`",
+                decoded
+            ))),
+        }
+    }
+
+    /// List the lease ids and sub-prefixes directly under `prefix`.  This
+    /// requires `sudo` privileges.  Corresponds to
+    /// [`/sys/leases/lookup/:prefix`][list].
+    ///
+    /// ```no_run
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// let leases = client.list_leases("database/creds/readonly/").unwrap();
+    /// ```
+    ///
+    /// [list]: https://www.vaultproject.io/api-docs/system/leases#list-leases
+    pub fn list_leases(&self, prefix: &str) -> Result<Vec<String>> {
+        let res =
+            self.list::<_, String>(&format!("/v1/sys/leases/lookup/{}", prefix)[..], None, None)?;
+        let decoded: VaultResponse<ListResponse> = parse_vault_response(res)?;
+        match decoded.data {
+            Some(data) => Ok(data.keys),
+            _ => Err(Error::Vault(format!(
+                "No leases found in response: `{:#?}`",
+                decoded
+            ))),
+        }
+    }
+
+    /// Revoke a lease, invalidating its secret.  When `sync` is false,
+    /// vault may revoke the lease in the background and return immediately.
+    /// Corresponds to [`/sys/leases/revoke`][revoke].
+    ///
+    /// ```no_run
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// client.revoke_lease("database/creds/readonly/abcdef", true).unwrap();
+    /// ```
+    ///
+    /// [revoke]: https://www.vaultproject.io/api-docs/system/leases#revoke-lease
+    pub fn revoke_lease(&self, lease_id: &str, sync: bool) -> Result<()> {
+        let body = serde_json::to_string(&RevokeLeasePayload { lease_id })?;
+        let _ = self.put_with_query::<_, String>(
+            "/v1/sys/leases/revoke",
+            &[("sync", if sync { "true" } else { "false" })],
+            Some(&body),
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Revoke every lease whose id starts with `prefix`, e.g. all
+    /// credentials issued by a database mount.  This requires `sudo`
+    /// privileges.  When `sync` is false, vault may revoke the leases in the
+    /// background and return immediately.  Corresponds to
+    /// [`/sys/leases/revoke-prefix`][revoke-prefix].
+    ///
+    /// ```no_run
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// client.revoke_lease_prefix("database/creds/", false).unwrap();
+    /// ```
+    ///
+    /// [revoke-prefix]: https://www.vaultproject.io/api-docs/system/leases#revoke-prefix
+    pub fn revoke_lease_prefix(&self, prefix: &str, sync: bool) -> Result<()> {
+        let _ = self.put_with_query::<_, String>(
+            &format!("/v1/sys/leases/revoke-prefix/{}", prefix)[..],
+            &[("sync", if sync { "true" } else { "false" })],
+            None,
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Lookup token information for this client's token.  Corresponds to
+    /// [`/auth/token/lookup-self`][token].
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// let res = client.lookup().unwrap();
+    /// assert!(res.data.unwrap().policies.len() >= 0);
+    /// ```
+    ///
+    /// [token]: https://www.vaultproject.io/docs/auth/token.html
+    pub fn lookup(&self) -> Result<VaultResponse<TokenData>> {
+        let res = self.get::<_, String>("/v1/auth/token/lookup-self", None)?;
+        let vault_res: VaultResponse<TokenData> = parse_vault_response(res)?;
+        Ok(vault_res)
+    }
+
+    /// Check whether this client's token is still valid by looking it up.
+    /// An expired or revoked token is reported as `false` rather than an
+    /// error; other errors, e.g. failing to connect, are still returned.
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// assert!(client.is_token_valid().unwrap());
+    /// ```
+    pub fn is_token_valid(&self) -> Result<bool> {
+        match self.lookup() {
+            Ok(_) => Ok(true),
+            Err(ref e) if e.is_permission_denied() => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Lookup token information for the token with the given `accessor`,
+    /// without needing the token itself.  Corresponds to
+    /// [`/auth/token/lookup-accessor`][token].
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::{client, Client};
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// let opts = client::TokenOptions::default()
+    ///   .ttl(client::VaultDuration::minutes(1));
+    /// let res = client.create_token(&opts).unwrap();
+    /// let info = client.lookup_accessor(res.accessor.unwrap()).unwrap();
+    /// assert!(info.data.unwrap().policies.len() > 0);
+    /// ```
+    ///
+    /// [token]: https://www.vaultproject.io/docs/auth/token.html
+    pub fn lookup_accessor<S: AsRef<str>>(&self, accessor: S) -> Result<VaultResponse<TokenData>> {
+        let body = serde_json::to_string(&AccessorOptions {
+            accessor: accessor.as_ref(),
+        })?;
+        let res = self.post::<_, String>("/v1/auth/token/lookup-accessor", Some(&body), None)?;
+        parse_vault_response(res)
+    }
+
+    /// Create a new vault token using the specified options.  Corresponds to
+    /// [`/auth/token/create`][token].
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::{client, Client};
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// let opts = client::TokenOptions::default()
+    ///   .display_name("test_token")
+    ///   .policies(vec!("root"))
+    ///   .default_policy(false)
+    ///   .orphan(true)
+    ///   .renewable(false)
+    ///   .display_name("jdoe-temp")
+    ///   .number_of_uses(10)
+    ///   .ttl(client::VaultDuration::minutes(1))
+    ///   .explicit_max_ttl(client::VaultDuration::minutes(3));
+    /// let res = client.create_token(&opts).unwrap();
+    ///
+    /// # let new_client = Client::new(host, res.client_token).unwrap();
+    /// # new_client.revoke().unwrap();
+    /// ```
+    ///
+    /// [token]: https://www.vaultproject.io/docs/auth/token.html
+    pub fn create_token(&self, opts: &TokenOptions) -> Result<Auth> {
+        self.create_token_at("/v1/auth/token/create", opts)
+    }
+
+    /// Create a new orphan vault token using the specified options.
+    /// Corresponds to [`/auth/token/create-orphan`][token].
+    ///
+    /// Unlike `create_token` with `TokenOptions::orphan(true)`, this does not
+    /// require `sudo` or `root` privileges, only access to the endpoint.
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::{client, Client};
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// let opts = client::TokenOptions::default()
+    ///   .ttl(client::VaultDuration::minutes(1));
+    /// let res = client.create_orphan_token(&opts).unwrap();
+    ///
+    /// # let new_client = Client::new(host, res.client_token).unwrap();
+    /// # new_client.revoke().unwrap();
+    /// ```
+    ///
+    /// [token]: https://www.vaultproject.io/docs/auth/token.html
+    pub fn create_orphan_token(&self, opts: &TokenOptions) -> Result<Auth> {
+        self.create_token_at("/v1/auth/token/create-orphan", opts)
+    }
+
+    /// Create a new vault token against the token role `role`, which
+    /// constrains the options the token may be created with.  Corresponds to
+    /// [`/auth/token/create/:role_name`][token].
+    ///
+    /// ```no_run
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::{client, Client};
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// let opts = client::TokenOptions::default().display_name("batch-job");
+    /// let res = client.create_token_with_role("batch-jobs", &opts).unwrap();
+    /// ```
+    ///
+    /// [token]: https://www.vaultproject.io/docs/auth/token.html
+    pub fn create_token_with_role(&self, role: &str, opts: &TokenOptions) -> Result<Auth> {
+        self.create_token_at(&format!("/v1/auth/token/create/{}", role), opts)
+    }
+
+    fn create_token_at(&self, endpoint: &str, opts: &TokenOptions) -> Result<Auth> {
+        let body = serde_json::to_string(opts)?;
+        let res = self.post::<_, String>(endpoint, Some(&body), None)?;
+        let vault_res: VaultResponse<()> = parse_vault_response(res)?;
+        vault_res
+            .auth
+            .ok_or_else(|| Error::Vault("Created token did not include auth data".into()))
+    }
+
+    ///
+    /// Saves a secret
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// let res = client.set_secret("hello_set", "world");
+    /// assert!(res.is_ok());
+    /// ```
+    pub fn set_secret<S1: Into<String>, S2: AsRef<str>>(&self, key: S1, value: S2) -> Result<()> {
+        let secret = DefaultSecretType {
+            value: value.as_ref(),
+        };
+        self.set_custom_secret(key, &secret)
+    }
+
+    /// Saves a secret
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// #[derive(Deserialize, Serialize)]
+    /// struct MyThing {
+    ///   awesome: String,
+    ///   thing: String,
+    /// }
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// let secret = MyThing {
+    ///   awesome: "I really am cool".into(),
+    ///   thing: "this is also in the secret".into(),
+    /// };
+    /// let res = client.set_custom_secret("hello_set", &secret);
+    /// assert!(res.is_ok());
+    /// ```
+    pub fn set_custom_secret<S1, S2>(&self, secret_name: S1, secret: &S2) -> Result<()>
+    where
+        S1: Into<String>,
+        S2: Serialize,
+    {
+        let secret = SecretContainer { data: secret };
+        let json = serde_json::to_string(&secret)?;
+        let _ = self.put::<_, String>(
+            &format!("/v1/{}/data/{}", self.secret_backend, secret_name.into())[..],
+            Some(&json),
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Saves a secret made up of multiple fields
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    /// use std::collections::HashMap;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// let mut secret = HashMap::new();
+    /// secret.insert("username".to_string(), "jdoe".to_string());
+    /// secret.insert("password".to_string(), "hunter2".to_string());
+    /// let res = client.set_secret_map("hello_map", &secret);
+    /// assert!(res.is_ok());
+    /// ```
+    pub fn set_secret_map<S: Into<String>>(
+        &self,
+        key: S,
+        data: &HashMap<String, String>,
+    ) -> Result<()> {
+        self.set_custom_secret(key, data)
+    }
+
+    ///
+    /// List secrets at specified path
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// let res = client.set_secret("hello/fred", "world");
+    /// assert!(res.is_ok());
+    /// let res = client.set_secret("hello/bob", "world");
+    /// assert!(res.is_ok());
+    /// let res = client.list_secrets("hello/");
+    /// assert!(res.is_ok());
+    /// assert_eq!(res.unwrap(), ["bob", "fred"]);
+    /// ```
+    pub fn list_secrets<S: AsRef<str>>(&self, key: S) -> Result<Vec<String>> {
+        self.list_secrets_v2(None, key)
+    }
+
+    /// Lists the keys under `key` in a KV v1 secret backend.  Keys of
+    /// "directories" keep their trailing `/`, so they can be listed in turn.
+    /// `mountpoint` defaults to the client's `secret_backend`.
+    pub fn list_secrets_v1<S: AsRef<str>>(
+        &self,
+        mountpoint: Option<String>,
+        key: S,
+    ) -> Result<Vec<String>> {
+        let path = mountpoint.unwrap_or_else(|| self.secret_backend.clone());
+        self.list_keys(&format!("/v1/{}/{}", path, key.as_ref()))
+    }
+
+    /// Lists the keys under `key` in a KV v2 secret backend.  Keys of
+    /// "directories" keep their trailing `/`, so they can be listed in turn.
+    /// `mountpoint` defaults to the client's `secret_backend`.
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// client.set_secret("hello_list/dir/fred", "world").unwrap();
+    /// client.set_secret("hello_list/bob", "world").unwrap();
+    /// let res = client.list_secrets_v2(None, "hello_list/").unwrap();
+    /// assert_eq!(res, ["bob", "dir/"]);
+    /// ```
+    pub fn list_secrets_v2<S: AsRef<str>>(
+        &self,
+        mountpoint: Option<String>,
+        key: S,
+    ) -> Result<Vec<String>> {
+        let path = mountpoint.unwrap_or_else(|| self.secret_backend.clone());
+        self.list_keys(&format!("/v1/{}/metadata/{}", path, key.as_ref()))
+    }
+
+    fn list_keys(&self, endpoint: &str) -> Result<Vec<String>> {
+        let res = self.list::<_, String>(endpoint, None, None)?;
+        let decoded: VaultResponse<ListResponse> = parse_vault_response(res)?;
+        match decoded.data {
+            Some(data) => Ok(data.keys),
+            _ => Err(Error::Vault(format!(
+                "No secrets found in response: `{:#?}`",
+                decoded
+            ))),
+        }
+    }
+
+    ///
+    /// Fetches a saved secret
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// let res = client.set_secret("hello_get", "world");
+    /// assert!(res.is_ok());
+    /// let res = client.get_secret("hello_get");
+    /// assert!(res.is_ok());
+    /// assert_eq!(res.unwrap(), "world");
+    /// ```
+    pub fn get_secret<S: AsRef<str>>(&self, key: S) -> Result<String> {
+        let decoded = self.get_secret_full(key)?;
+        match decoded.data {
+            Some(data) => Ok(data.value),
+            _ => Err(Error::Vault(format!(
+                "No secret found in response: `{:#?}`",
+                decoded
+            ))),
+        }
+    }
+
+    /// Fetches a saved secret, as `get_secret` does, but serves it from the
+    /// client's cache until its lease expires.  The cache must first be
+    /// enabled with `enable_secret_cache`; until then this always fetches.
+    /// Secrets are cached per namespace and secret backend, so switching
+    /// either with `set_namespace` or `secret_backend` never serves secrets
+    /// read from the previous one.
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    /// use std::time::Duration;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let mut client = Client::new(host, token).unwrap();
+    /// client.enable_secret_cache(Duration::from_secs(60));
+    /// client.set_secret("hello_cached", "world").unwrap();
+    /// assert_eq!(client.get_secret_cached("hello_cached").unwrap(), "world");
+    ///
+    /// client.set_secret("hello_cached", "new world").unwrap();
+    /// assert_eq!(client.get_secret_cached("hello_cached").unwrap(), "world");
+    /// client.invalidate("hello_cached");
+    /// assert_eq!(client.get_secret_cached("hello_cached").unwrap(), "new world");
+    /// ```
+    pub fn get_secret_cached<S: AsRef<str>>(&self, key: S) -> Result<String> {
+        let key = key.as_ref();
+        let cache = match self.secret_cache {
+            Some(ref cache) => cache,
+            None => return self.get_secret(key),
+        };
+        let cache_key = self.secret_cache_key(key);
+        let now = Instant::now();
+        if let Some((expires, value)) = cache.lock().get(&cache_key) {
+            if *expires > now {
+                return Ok(value.clone());
+            }
+        }
+        let decoded = self.get_secret_full(key)?;
+        let ttl = match decoded.lease_duration {
+            Some(VaultDuration(ttl)) if ttl > Duration::from_secs(0) => ttl,
+            _ => cache.default_ttl,
+        };
+        match decoded.data {
+            Some(data) => {
+                let _ = cache
+                    .lock()
+                    .insert(cache_key, (now + ttl, data.value.clone()));
+                Ok(data.value)
+            }
+            _ => Err(Error::Vault(format!(
+                "No secret found in response: `{:#?}`",
+                decoded
+            ))),
+        }
+    }
+
+    /// Remove a secret of the current namespace and secret backend from the
+    /// cache used by `get_secret_cached`, e.g. after updating it, so the
+    /// next read fetches it again.
+    pub fn invalidate<S: AsRef<str>>(&self, key: S) {
+        if let Some(ref cache) = self.secret_cache {
+            let _ = cache.lock().remove(&self.secret_cache_key(key.as_ref()));
+        }
+    }
+
+    fn secret_cache_key(&self, key: &str) -> SecretCacheKey {
+        (
+            self.namespace.clone(),
+            self.secret_backend.clone(),
+            key.to_owned(),
+        )
+    }
+
+    /// Fetches a saved secret along with the rest of the `VaultResponse`,
+    /// e.g. its lease duration, to know when a cached copy should be
+    /// refreshed.
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// client.set_secret("hello_get_full", "world").unwrap();
+    /// let res = client.get_secret_full("hello_get_full").unwrap();
+    /// assert_eq!(res.data.unwrap().value, "world");
+    /// ```
+    pub fn get_secret_full<S: AsRef<str>>(&self, key: S) -> Result<VaultResponse<SecretData>> {
+        let res = self
+            .get::<_, String>(
+                &format!("/v1/{}/data/{}", self.secret_backend, key.as_ref())[..],
+                None,
+            )
+            .map_err(|e| secret_not_found(e, key.as_ref()))?;
+        let decoded: VaultResponse<SecretDataWrapper<SecretData>> = parse_vault_response(res)?;
+        Ok(decoded.into())
+    }
+
+    ///
+    /// Fetches a saved secret
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// #[derive(Debug, Deserialize, Serialize)]
+    /// struct MyThing {
+    ///   awesome: String,
+    ///   thing: String,
+    /// }
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// let secret = MyThing {
+    ///   awesome: "I really am cool".into(),
+    ///   thing: "this is also in the secret".into(),
+    /// };
+    /// let res1 = client.set_custom_secret("custom_secret", &secret);
+    /// assert!(res1.is_ok());
+    /// let res2: Result<MyThing, _> = client.get_custom_secret("custom_secret");
+    /// assert!(res2.is_ok());
+    /// let thing = res2.unwrap();
+    /// assert_eq!(thing.awesome, "I really am cool");
+    /// assert_eq!(thing.thing, "this is also in the secret");
+    /// ```
+    pub fn get_custom_secret<S: AsRef<str>, S2: DeserializeOwned + fmt::Debug>(
+        &self,
+        secret_name: S,
+    ) -> Result<S2> {
+        let res = self
+            .get::<_, String>(
+                &format!("/v1/{}/data/{}", self.secret_backend, secret_name.as_ref())[..],
+                None,
+            )
+            .map_err(|e| secret_not_found(e, secret_name.as_ref()))?;
+        let decoded: VaultResponse<SecretDataWrapper<S2>> = parse_vault_response(res)?;
+        match decoded.data {
+            Some(data) => Ok(data.data),
+            _ => Err(Error::Vault(format!(
+                "No secret found in response: `{:#?}`",
+                decoded
+            ))),
+        }
+    }
+
+    /// Fetches a saved secret made up of multiple fields
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    /// use std::collections::HashMap;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// let mut secret = HashMap::new();
+    /// secret.insert("username".to_string(), "jdoe".to_string());
+    /// secret.insert("password".to_string(), "hunter2".to_string());
+    /// client.set_secret_map("hello_get_map", &secret).unwrap();
+    /// let res = client.get_secret_map("hello_get_map").unwrap();
+    /// assert_eq!(res["username"], "jdoe");
+    /// ```
+    pub fn get_secret_map<S: AsRef<str>>(&self, key: S) -> Result<HashMap<String, String>> {
+        self.get_custom_secret(key)
+    }
+
+    /// Saves a secret to a KV v2 secret backend, returning the metadata of
+    /// the newly created version.  `mountpoint` defaults to the client's
+    /// `secret_backend`.
+    ///
+    /// `set_secret` and `set_custom_secret` also write to KV v2 backends, but
+    /// discard the version metadata.
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    /// use std::collections::HashMap;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// let mut secret = HashMap::new();
+    /// secret.insert("password", "hunter2");
+    /// let metadata = client.set_secret_v2(None, "hello_v2", &secret).unwrap();
+    /// assert!(metadata.version >= 1);
+    /// ```
+    pub fn set_secret_v2<S: AsRef<str>, D: Serialize>(
+        &self,
+        mountpoint: Option<String>,
+        key: S,
+        data: &D,
+    ) -> Result<KvV2Metadata> {
+        let json = serde_json::to_string(&SecretContainer { data })?;
+        self.write_secret_v2(mountpoint, key.as_ref(), &json)
+    }
+
+    /// Saves a secret to a KV v2 secret backend only if its current version
+    /// is `cas`, or if it doesn't exist when `cas` is 0, returning the
+    /// metadata of the newly created version.  Otherwise fails with
+    /// `Error::CasMismatch`, so read-modify-write loops can retry.
+    /// `mountpoint` defaults to the client's `secret_backend`.
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::{Client, Error};
+    /// use std::collections::HashMap;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// let mut secret = HashMap::new();
+    /// secret.insert("counter", "1");
+    /// let metadata = client.set_secret_v2(None, "hello_cas", &secret).unwrap();
+    ///
+    /// secret.insert("counter", "2");
+    /// client.set_secret_cas(None, "hello_cas", &secret, metadata.version).unwrap();
+    /// match client.set_secret_cas(None, "hello_cas", &secret, metadata.version) {
+    ///     Err(Error::CasMismatch(_)) => {}
+    ///     res => panic!("expected a CAS mismatch, got {:?}", res),
+    /// }
+    /// ```
+    pub fn set_secret_cas<S: AsRef<str>, D: Serialize>(
+        &self,
+        mountpoint: Option<String>,
+        key: S,
+        data: &D,
+        cas: u64,
+    ) -> Result<KvV2Metadata> {
+        let json = serde_json::to_string(&CasSecretContainer {
+            options: CasOptions { cas },
+            data,
+        })?;
+        self.write_secret_v2(mountpoint, key.as_ref(), &json)
+            .map_err(|e| match e {
+                Error::VaultApi {
+                    status: 400,
+                    ref errors,
+                } if errors.iter().any(|e| e.contains("check-and-set")) => {
+                    Error::CasMismatch(key.as_ref().to_owned())
+                }
+                e => e,
+            })
+    }
+
+    fn write_secret_v2(
+        &self,
+        mountpoint: Option<String>,
+        key: &str,
+        json: &str,
+    ) -> Result<KvV2Metadata> {
+        let path = mountpoint.unwrap_or_else(|| self.secret_backend.clone());
+        let res =
+            self.post::<_, String>(&format!("/v1/{}/data/{}", path, key)[..], Some(json), None)?;
+        let decoded: VaultResponse<KvV2Metadata> = parse_vault_response(res)?;
+        match decoded.data {
+            Some(data) => Ok(data),
+            _ => Err(Error::Vault(format!(
+                "No secret metadata found in response: `{:#?}`",
+                decoded
+            ))),
+        }
+    }
+
+    /// Fetches the latest version of a secret from a KV v2 secret backend,
+    /// along with its metadata.  `mountpoint` defaults to the client's
+    /// `secret_backend`.
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    /// use std::collections::HashMap;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// let mut secret = HashMap::new();
+    /// secret.insert("password".to_string(), "hunter2".to_string());
+    /// client.set_secret_v2(None, "hello_get_v2", &secret).unwrap();
+    /// let res = client.get_secret_v2::<_, HashMap<String, String>>(None, "hello_get_v2").unwrap();
+    /// assert_eq!(res.data, secret);
+    /// assert!(!res.metadata.destroyed);
+    /// ```
+    pub fn get_secret_v2<S: AsRef<str>, D: DeserializeOwned>(
+        &self,
+        mountpoint: Option<String>,
+        key: S,
+    ) -> Result<KvV2Secret<D>> {
+        self.read_secret_v2(mountpoint, key.as_ref(), &[])
+    }
+
+    /// Fetches a specific version of a secret from a KV v2 secret backend,
+    /// along with its metadata.  `mountpoint` defaults to the client's
+    /// `secret_backend`.
+    pub fn get_secret_version<S: AsRef<str>, D: DeserializeOwned>(
+        &self,
+        mountpoint: Option<String>,
+        key: S,
+        version: u64,
+    ) -> Result<KvV2Secret<D>> {
+        let version = version.to_string();
+        self.read_secret_v2(mountpoint, key.as_ref(), &[("version", &version)])
+    }
+
+    /// Reads the metadata of every version of a secret in a KV v2 secret
+    /// backend, along with its configuration.  `mountpoint` defaults to the
+    /// client's `secret_backend`.
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    /// use std::collections::HashMap;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// let mut secret = HashMap::new();
+    /// secret.insert("password", "hunter2");
+    /// client.set_secret_v2(None, "hello_metadata", &secret).unwrap();
+    /// client.set_secret_metadata(None, "hello_metadata", 5, false).unwrap();
+    ///
+    /// let metadata = client.read_secret_metadata(None, "hello_metadata").unwrap();
+    /// assert_eq!(metadata.max_versions, 5);
+    /// assert!(metadata.versions.contains_key(&metadata.current_version.to_string()));
+    /// ```
+    pub fn read_secret_metadata<S: AsRef<str>>(
+        &self,
+        mountpoint: Option<String>,
+        key: S,
+    ) -> Result<KvV2FullMetadata> {
+        let path = mountpoint.unwrap_or_else(|| self.secret_backend.clone());
+        let res = self
+            .get::<_, String>(&format!("/v1/{}/metadata/{}", path, key.as_ref())[..], None)
+            .map_err(|e| secret_not_found(e, key.as_ref()))?;
+        let decoded: VaultResponse<KvV2FullMetadata> = parse_vault_response(res)?;
+        match decoded.data {
+            Some(data) => Ok(data),
+            _ => Err(Error::Vault(format!(
+                "No secret metadata found in response: `{:#?}`",
+                decoded
+            ))),
+        }
+    }
+
+    /// Configures a secret in a KV v2 secret backend: the number of
+    /// versions to keep, or 0 to use the backend's setting, and whether
+    /// writes must use check-and-set.  `mountpoint` defaults to the client's
+    /// `secret_backend`.
+    pub fn set_secret_metadata<S: AsRef<str>>(
+        &self,
+        mountpoint: Option<String>,
+        key: S,
+        max_versions: u64,
+        cas_required: bool,
+    ) -> Result<()> {
+        let path = mountpoint.unwrap_or_else(|| self.secret_backend.clone());
+        let body = serde_json::to_string(&KvV2MetadataPayload {
+            max_versions,
+            cas_required,
+        })?;
+        let _ = self.post::<_, String>(
+            &format!("/v1/{}/metadata/{}", path, key.as_ref())[..],
+            Some(&body),
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Soft-deletes versions of a secret in a KV v2 secret backend.  Their
+    /// data can be restored with `undelete_secret_versions`.  `mountpoint`
+    /// defaults to the client's `secret_backend`.
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    /// use std::collections::HashMap;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// let mut secret = HashMap::new();
+    /// secret.insert("password", "hunter2");
+    /// let metadata = client.set_secret_v2(None, "hello_versions", &secret).unwrap();
+    ///
+    /// client.delete_secret_versions(None, "hello_versions", &[metadata.version]).unwrap();
+    /// let res = client.get_secret_version::<_, HashMap<String, String>>(
+    ///     None, "hello_versions", metadata.version);
+    /// assert!(res.unwrap_err().is_not_found());
+    ///
+    /// client.undelete_secret_versions(None, "hello_versions", &[metadata.version]).unwrap();
+    /// let res = client.get_secret_version::<_, HashMap<String, String>>(
+    ///     None, "hello_versions", metadata.version);
+    /// assert_eq!(res.unwrap().data["password"], "hunter2");
+    /// ```
+    pub fn delete_secret_versions<S: AsRef<str>>(
+        &self,
+        mountpoint: Option<String>,
+        key: S,
+        versions: &[u64],
+    ) -> Result<()> {
+        self.secret_versions_action(mountpoint, "delete", key.as_ref(), versions)
+    }
+
+    /// Restores soft-deleted versions of a secret in a KV v2 secret
+    /// backend.  `mountpoint` defaults to the client's `secret_backend`.
+    pub fn undelete_secret_versions<S: AsRef<str>>(
+        &self,
+        mountpoint: Option<String>,
+        key: S,
+        versions: &[u64],
+    ) -> Result<()> {
+        self.secret_versions_action(mountpoint, "undelete", key.as_ref(), versions)
+    }
+
+    /// Permanently destroys the data of versions of a secret in a KV v2
+    /// secret backend.  `mountpoint` defaults to the client's
+    /// `secret_backend`.
+    pub fn destroy_secret_versions<S: AsRef<str>>(
+        &self,
+        mountpoint: Option<String>,
+        key: S,
+        versions: &[u64],
+    ) -> Result<()> {
+        self.secret_versions_action(mountpoint, "destroy", key.as_ref(), versions)
+    }
+
+    fn secret_versions_action(
+        &self,
+        mountpoint: Option<String>,
+        action: &str,
+        key: &str,
+        versions: &[u64],
+    ) -> Result<()> {
+        let path = mountpoint.unwrap_or_else(|| self.secret_backend.clone());
+        let body = serde_json::to_string(&KvV2VersionsPayload { versions })?;
+        let _ = self.post::<_, String>(
+            &format!("/v1/{}/{}/{}", path, action, key)[..],
+            Some(&body),
+            None,
+        )?;
+        Ok(())
+    }
+
+    fn read_secret_v2<D: DeserializeOwned>(
+        &self,
+        mountpoint: Option<String>,
+        key: &str,
+        query: &[(&str, &str)],
+    ) -> Result<KvV2Secret<D>> {
+        let path = mountpoint.unwrap_or_else(|| self.secret_backend.clone());
+        let res = self
+            .get_with_query::<_, String>(&format!("/v1/{}/data/{}", path, key)[..], query, None)
+            .map_err(|e| secret_not_found(e, key))?;
+        let decoded: VaultResponse<KvV2Secret<D>> = parse_vault_response(res)?;
+        match decoded.data {
+            Some(data) => Ok(data),
+            _ => Err(Error::Vault(format!(
+                "No secret found in response to request `{}`",
+                decoded.request_id.unwrap_or_default()
+            ))),
+        }
+    }
+
+    /// Fetch a wrapped secret. Token (one-time use) to fetch secret will be in `wrap_info.token`
+    /// https://www.vaultproject.io/docs/secrets/cubbyhole/index.html
+    ///
+    /// `wrap_ttl` may be a `VaultDuration`, a `Duration` or a string such as
+    /// `"15m"`.
+    pub fn get_secret_wrapped<S: AsRef<str>, W: Into<WrapTtl>>(
+        &self,
+        key: S,
+        wrap_ttl: W,
+    ) -> Result<VaultResponse<()>> {
+        let res = self.get(
+            &format!("/v1/{}/data/{}", self.secret_backend, key.as_ref())[..],
+            Some(wrap_ttl.into().0),
+        )?;
+        parse_vault_response(res)
+    }
+
+    /// Using a vault client created from a wrapping token, fetch the unwrapped `VaultResponse` from
+    /// `sys/wrapping/unwrap`.
+    ///
+    /// The `data` attribute of `VaultResponse` should contain the unwrapped information, which is
+    /// returned as a `HashMap<String, String>`.
+    pub fn get_unwrapped_response(&self) -> Result<VaultResponse<HashMap<String, String>>> {
+        let res = self.post::<_, String>("/v1/sys/wrapping/unwrap", None, None)?;
+        let result: VaultResponse<SecretDataWrapper<HashMap<String, String>>> =
+            parse_vault_response(res)?;
+        Ok(result.into())
+    }
+
+    /// Look up the metadata of a wrapping token, e.g. to check it was
+    /// created by the expected path before unwrapping it.
+    pub fn wrapping_lookup(&self, token: &str) -> Result<WrappingLookup> {
+        let body = serde_json::to_string(&TokenIdOptions {
+            token: token.to_owned(),
+        })?;
+        let res = self.post::<_, String>("/v1/sys/wrapping/lookup", Some(&body), None)?;
+        let decoded: VaultResponse<WrappingLookup> = parse_vault_response(res)?;
+        match decoded.data {
+            Some(data) => Ok(data),
+            _ => Err(Error::Vault(format!(
+                "No wrapping token found in response: `{:#?}`",
+                decoded
+            ))),
+        }
+    }
+
+    /// Rewrap the response wrapped by `token` in a new wrapping token with
+    /// a fresh TTL.  The old token is revoked.
+    pub fn wrapping_rewrap(&self, token: &str) -> Result<WrapInfo> {
+        let body = serde_json::to_string(&TokenIdOptions {
+            token: token.to_owned(),
+        })?;
+        let res = self.post::<_, String>("/v1/sys/wrapping/rewrap", Some(&body), None)?;
+        let decoded: VaultResponse<()> = parse_vault_response(res)?;
+        match decoded.wrap_info {
+            Some(wrap_info) => Ok(wrap_info),
+            _ => Err(Error::Vault(format!(
+                "No wrap info found in response to request `{}`",
+                decoded.request_id.unwrap_or_default()
+            ))),
+        }
+    }
+
+    /// Wrap arbitrary `data` in a wrapping token valid for `ttl`, to hand it
+    /// off without storing it in a secret engine.  `ttl` may be a
+    /// `VaultDuration`, a `Duration` or a string such as `"5m"`.
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    /// use std::collections::HashMap;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// let mut data = HashMap::new();
+    /// let _ = data.insert("password", "hunter2");
+    /// let wrap_info = client.wrap(&data, "5m").unwrap();
+    /// assert_eq!(wrap_info.ttl.0.as_secs(), 300);
+    /// ```
+    pub fn wrap<D: Serialize, W: Into<WrapTtl>>(&self, data: &D, ttl: W) -> Result<WrapInfo> {
+        let body = serde_json::to_string(data)?;
+        let res = self.post("/v1/sys/wrapping/wrap", Some(&body), Some(ttl.into().0))?;
+        let decoded: VaultResponse<()> = parse_vault_response(res)?;
+        match decoded.wrap_info {
+            Some(wrap_info) => Ok(wrap_info),
+            _ => Err(Error::Vault(format!(
+                "No wrap info found in response to request `{}`",
+                decoded.request_id.unwrap_or_default()
+            ))),
+        }
+    }
+
+    /// Reads the properties of an existing `AppRole`.
+    pub fn get_app_role_properties<S: AsRef<str>>(
+        &self,
+        role_name: S,
+    ) -> Result<VaultResponse<AppRoleProperties>> {
+        let res = self.get::<_, String>(
+            &format!("/v1/auth/approle/role/{}", role_name.as_ref()),
+            None,
+        )?;
+        parse_vault_response(res)
+    }
+
+    /// Create or update the `AppRole` `role_name` with the given properties.
+    /// The `AppRole` auth backend must be enabled at `approle`.
+    pub fn create_app_role<S: AsRef<str>>(
+        &self,
+        role_name: S,
+        props: &AppRoleProperties,
+    ) -> Result<()> {
+        let body = serde_json::to_string(props)?;
+        let _ = self.post::<_, String>(
+            &format!("/v1/auth/approle/role/{}", role_name.as_ref())[..],
+            Some(&body),
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Reads the role ID of an existing `AppRole`.
+    pub fn read_role_id<S: AsRef<str>>(&self, role_name: S) -> Result<String> {
+        let res = self.get::<_, String>(
+            &format!("/v1/auth/approle/role/{}/role-id", role_name.as_ref()),
+            None,
+        )?;
+        let decoded: VaultResponse<RoleIdData> = parse_vault_response(res)?;
+        match decoded.data {
+            Some(data) => Ok(data.role_id),
+            _ => Err(Error::Vault(format!(
+                "No role id found in response: `{:#?}`",
+                decoded
+            ))),
+        }
+    }
+
+    /// Generates a new secret ID for an existing `AppRole`.
+    pub fn generate_secret_id<S: AsRef<str>>(&self, role_name: S) -> Result<SecretIdResponse> {
+        let res = self.post::<_, String>(
+            &format!("/v1/auth/approle/role/{}/secret-id", role_name.as_ref()),
+            None,
+            None,
+        )?;
+        let decoded: VaultResponse<SecretIdResponse> = parse_vault_response(res)?;
+        match decoded.data {
+            Some(data) => Ok(data),
+            _ => Err(Error::Vault(format!(
+                "No secret id found in response: `{:#?}`",
+                decoded
+            ))),
+        }
+    }
+
+    /// Create a new named key of the given `key_type` (e.g. `aes256-gcm96`)
+    /// via Transit secret backend.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// let res = client.transit_create_key(None, "keyname", "aes256-gcm96");
+    /// assert!(res.is_ok());
+    /// ```
+    pub fn transit_create_key<S: AsRef<str>>(
+        &self,
+        mountpoint: Option<String>,
+        name: S,
+        key_type: &str,
+    ) -> Result<()> {
+        let path = mountpoint.unwrap_or_else(|| "transit".to_owned());
+        let body = serde_json::to_string(&TransitCreateKeyPayload { key_type })?;
+        let _ = self.post::<_, String>(
+            &format!("/v1/{}/keys/{}", path, name.as_ref())[..],
+            Some(&body),
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Reads the properties of a named key via Transit secret backend.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// client.transit_create_key(None, "keyname", "aes256-gcm96").unwrap();
+    /// let res = client.transit_read_key(None, "keyname").unwrap();
+    /// assert_eq!(res.data.unwrap().key_type, "aes256-gcm96");
+    /// ```
+    pub fn transit_read_key<S: AsRef<str>>(
+        &self,
+        mountpoint: Option<String>,
+        name: S,
+    ) -> Result<VaultResponse<TransitKeyInfo>> {
+        let path = mountpoint.unwrap_or_else(|| "transit".to_owned());
+        let res =
+            self.get::<_, String>(&format!("/v1/{}/keys/{}", path, name.as_ref())[..], None)?;
+        parse_vault_response(res)
+    }
+
+    /// Rotate a named key via Transit secret backend.  New encryptions use
+    /// the new key version; existing ciphertext can be moved to it with
+    /// `transit_rewrap`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// client.transit_create_key(None, "keyname", "aes256-gcm96").unwrap();
+    /// let res = client.transit_rotate_key(None, "keyname");
+    /// assert!(res.is_ok());
+    /// ```
+    pub fn transit_rotate_key<S: AsRef<str>>(
+        &self,
+        mountpoint: Option<String>,
+        name: S,
+    ) -> Result<()> {
+        let path = mountpoint.unwrap_or_else(|| "transit".to_owned());
+        let _ = self.post::<_, String>(
+            &format!("/v1/{}/keys/{}/rotate", path, name.as_ref())[..],
+            None,
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Update the configuration of a named key via Transit secret backend.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::{client::TransitKeyConfig, Client};
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// client.transit_create_key(None, "keyname", "aes256-gcm96").unwrap();
+    /// let config = TransitKeyConfig::default().deletion_allowed(false);
+    /// let res = client.transit_update_key_config(None, "keyname", &config);
+    /// assert!(res.is_ok());
+    /// ```
+    pub fn transit_update_key_config<S: AsRef<str>>(
+        &self,
+        mountpoint: Option<String>,
+        name: S,
+        config: &TransitKeyConfig,
+    ) -> Result<()> {
+        let path = mountpoint.unwrap_or_else(|| "transit".to_owned());
+        let body = serde_json::to_string(config)?;
+        let _ = self.post::<_, String>(
+            &format!("/v1/{}/keys/{}/config", path, name.as_ref())[..],
+            Some(&body),
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Delete a named key via Transit secret backend.  Keys can only be
+    /// deleted once `deletion_allowed` is set with
+    /// `transit_update_key_config`; otherwise vault responds with
+    /// `400 Bad Request`, returned as `Error::VaultApi`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::{client::TransitKeyConfig, Client};
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// client.transit_create_key(None, "doomed-key", "aes256-gcm96").unwrap();
+    /// let config = TransitKeyConfig::default().deletion_allowed(true);
+    /// client.transit_update_key_config(None, "doomed-key", &config).unwrap();
+    /// let res = client.transit_delete_key(None, "doomed-key");
+    /// assert!(res.is_ok());
+    /// ```
+    pub fn transit_delete_key<S: AsRef<str>>(
+        &self,
+        mountpoint: Option<String>,
+        name: S,
+    ) -> Result<()> {
+        let path = mountpoint.unwrap_or_else(|| "transit".to_owned());
+        let _ = self.delete(&format!("/v1/{}/keys/{}", path, name.as_ref())[..])?;
+        Ok(())
+    }
+
+    /// Export the named key via Transit secret backend, returning the key
+    /// material mapped by key version.  `key_type` is one of
+    /// `encryption-key`, `signing-key` or `hmac-key`; all versions are
+    /// exported unless `version` is given.
+    ///
+    /// Only keys created with `exportable` set can be exported; for other
+    /// keys vault responds with `400 Bad Request`, returned as
+    /// `Error::VaultApi`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    /// use std::collections::HashMap;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// let mut key = HashMap::new();
+    /// let _ = key.insert("exportable", true);
+    /// client.write::<_, ()>("transit/keys/exportable-key", &key).unwrap();
+    /// let keys = client
+    ///     .transit_export_key(None, "encryption-key", "exportable-key", None)
+    ///     .unwrap();
+    /// assert!(keys.contains_key("1"));
+    /// ```
+    pub fn transit_export_key<S: AsRef<str>>(
+        &self,
+        mountpoint: Option<String>,
+        key_type: &str,
+        name: S,
+        version: Option<u32>,
+    ) -> Result<HashMap<String, String>> {
+        let path = mountpoint.unwrap_or_else(|| "transit".to_owned());
+        let mut endpoint = format!("/v1/{}/export/{}/{}", path, key_type, name.as_ref());
+        if let Some(version) = version {
+            endpoint = format!("{}/{}", endpoint, version);
+        }
+        let res = self.get::<_, String>(&endpoint[..], None)?;
+        let decoded: VaultResponse<TransitExportData> = parse_vault_response(res)?;
+        match decoded.data {
+            Some(data) => Ok(data.keys),
+            _ => Err(Error::Vault(format!(
+                "No exported keys found in response: `{:#?}`",
+                decoded
+            ))),
+        }
+    }
+
+    /// Encrypt a plaintext via Transit secret backend, always with version 1
+    /// of the named key.
+    ///
+    /// The returned bytes don't record the key version, so this pins the
+    /// version `transit_decrypt` assumes.  Rotating the key therefore has no
+    /// effect on these ciphertexts, and encryption fails once the key's
+    /// `min_encryption_version` is raised above 1.  Use
+    /// `transit_encrypt_versioned` instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// # #[allow(deprecated)]
+    /// let res = client.transit_encrypt(None, "keyname", b"plaintext");
+    /// ```
+    #[deprecated(
+        since = "2.2.0",
+        note = "the key version is lost, use `transit_encrypt_versioned`"
+    )]
+    pub fn transit_encrypt<S1: Into<String>, S2: AsRef<[u8]>>(
+        &self,
+        mountpoint: Option<String>,
+        key: S1,
+        plaintext: S2,
+    ) -> Result<Vec<u8>> {
+        let opts = TransitEncryptOptions::default().key_version(1);
+        let ciphertext = self.transit_encrypt_with_options(mountpoint, key, plaintext, &opts)?;
+        Ok(ciphertext.bytes)
+    }
+
+    /// Encrypt a plaintext via Transit secret backend, keeping track of the
+    /// key version that was used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// let res = client.transit_encrypt_versioned(None, "keyname", b"plaintext");
+    /// assert!(res.unwrap().version >= 1);
+    /// ```
+    pub fn transit_encrypt_versioned<S1: Into<String>, S2: AsRef<[u8]>>(
+        &self,
+        mountpoint: Option<String>,
+        key: S1,
+        plaintext: S2,
+    ) -> Result<TransitCiphertext> {
+        let payload = TransitEncryptPayload {
+            plaintext: base64::encode(plaintext.as_ref()),
+            context: None,
+            key_version: None,
+        };
+        self.transit_encrypt_payload(mountpoint, key.into(), &payload)
+    }
+
+    /// Encrypt a plaintext via Transit secret backend with a key derived
+    /// from `context`.  The key must have been created with `derived` set;
+    /// if `convergent_encryption` is set too, the same plaintext and context
+    /// always produce the same ciphertext.  The same `context` is needed to
+    /// decrypt, see `transit_decrypt_with_context`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    /// use std::collections::HashMap;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// let mut key = HashMap::new();
+    /// let _ = key.insert("derived", true);
+    /// let _ = key.insert("convergent_encryption", true);
+    /// client.write::<_, ()>("transit/keys/derived-key", &key).unwrap();
+    /// let ciphertext = client
+    ///     .transit_encrypt_with_context(None, "derived-key", b"plaintext", b"tenant-a")
+    ///     .unwrap();
+    /// let res = client.transit_decrypt_with_context(None, "derived-key", &ciphertext, b"tenant-a");
+    /// assert_eq!(res.unwrap(), b"plaintext");
+    /// ```
+    pub fn transit_encrypt_with_context<S1: Into<String>, S2: AsRef<[u8]>>(
+        &self,
+        mountpoint: Option<String>,
+        key: S1,
+        plaintext: S2,
+        context: &[u8],
+    ) -> Result<TransitCiphertext> {
+        let opts = TransitEncryptOptions::default().context(context);
+        self.transit_encrypt_with_options(mountpoint, key, plaintext, &opts)
+    }
+
+    /// Encrypt a plaintext via Transit secret backend with the given
+    /// options, keeping track of the key version that was used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::{client::TransitEncryptOptions, Client};
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// client.transit_create_key(None, "keyname", "aes256-gcm96").unwrap();
+    /// let opts = TransitEncryptOptions::default().key_version(1);
+    /// let ciphertext = client
+    ///     .transit_encrypt_with_options(None, "keyname", b"plaintext", &opts)
+    ///     .unwrap();
+    /// assert_eq!(ciphertext.version, 1);
+    /// ```
+    pub fn transit_encrypt_with_options<S1: Into<String>, S2: AsRef<[u8]>>(
+        &self,
+        mountpoint: Option<String>,
+        key: S1,
+        plaintext: S2,
+        opts: &TransitEncryptOptions,
+    ) -> Result<TransitCiphertext> {
+        let payload = TransitEncryptPayload {
+            plaintext: base64::encode(plaintext.as_ref()),
+            context: opts.context.clone(),
+            key_version: opts.key_version,
+        };
+        self.transit_encrypt_payload(mountpoint, key.into(), &payload)
+    }
+
+    fn transit_encrypt_payload(
+        &self,
+        mountpoint: Option<String>,
+        key: String,
+        payload: &TransitEncryptPayload,
+    ) -> Result<TransitCiphertext> {
+        let path = mountpoint.unwrap_or_else(|| "transit".to_owned());
+        let body = serde_json::to_string(payload)?;
+        let res = self.post::<_, String>(
+            &format!("/v1/{}/encrypt/{}", path, key)[..],
+            Some(&body),
+            None,
+        )?;
+        let decoded: VaultResponse<TransitEncryptedData> = parse_vault_response(res)?;
+        match decoded.data {
+            Some(data) => data.ciphertext.parse(),
+            _ => Err(Error::Vault(format!(
+                "No ciphertext found in response: `{:#?}`",
+                decoded
+            ))),
+        }
+    }
+
+    /// Decrypt a ciphertext produced by `transit_encrypt`, i.e. by version 1
+    /// of the named key, via Transit secret backend.  Ciphertexts of any
+    /// other key version can't be decrypted with this; use
+    /// `transit_decrypt_versioned` instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// # #[allow(deprecated)]
+    /// let res = client.transit_decrypt(None, "keyname", b"\x02af\x61bcb\x55d");
+    /// ```
+    #[deprecated(
+        since = "2.2.0",
+        note = "the key version is assumed to be 1, use `transit_decrypt_versioned`"
+    )]
+    pub fn transit_decrypt<S1: Into<String>, S2: AsRef<[u8]>>(
+        &self,
+        mountpoint: Option<String>,
+        key: S1,
+        ciphertext: S2,
+    ) -> Result<Vec<u8>> {
+        let ciphertext = TransitCiphertext {
+            version: 1,
+            bytes: ciphertext.as_ref().to_vec(),
+        };
+        self.transit_decrypt_versioned(mountpoint, key, &ciphertext)
+    }
+
+    /// Decrypt a ciphertext produced by any version of the named key via
+    /// Transit secret backend.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// let ciphertext = client
+    ///     .transit_encrypt_versioned(None, "keyname", b"plaintext")
+    ///     .unwrap();
+    /// let res = client.transit_decrypt_versioned(None, "keyname", &ciphertext);
+    /// assert_eq!(res.unwrap(), b"plaintext");
+    /// ```
+    pub fn transit_decrypt_versioned<S: Into<String>>(
+        &self,
+        mountpoint: Option<String>,
+        key: S,
+        ciphertext: &TransitCiphertext,
+    ) -> Result<Vec<u8>> {
+        let payload = TransitDecryptPayload {
+            ciphertext: ciphertext.to_string(),
+            context: None,
+        };
+        self.transit_decrypt_payload(mountpoint, key.into(), &payload)
+    }
+
+    /// Decrypt a ciphertext produced by `transit_encrypt_with_context` via
+    /// Transit secret backend, using the same `context`.
+    pub fn transit_decrypt_with_context<S: Into<String>>(
+        &self,
+        mountpoint: Option<String>,
+        key: S,
+        ciphertext: &TransitCiphertext,
+        context: &[u8],
+    ) -> Result<Vec<u8>> {
+        let payload = TransitDecryptPayload {
+            ciphertext: ciphertext.to_string(),
+            context: Some(base64::encode(context)),
+        };
+        self.transit_decrypt_payload(mountpoint, key.into(), &payload)
+    }
+
+    fn transit_decrypt_payload(
+        &self,
+        mountpoint: Option<String>,
+        key: String,
+        payload: &TransitDecryptPayload,
+    ) -> Result<Vec<u8>> {
+        let path = mountpoint.unwrap_or_else(|| "transit".to_owned());
+        let body = serde_json::to_string(payload)?;
+        let res = self.post::<_, String>(
+            &format!("/v1/{}/decrypt/{}", path, key)[..],
+            Some(&body),
+            None,
+        )?;
+        let decoded: VaultResponse<TransitDecryptedData> = parse_vault_response(res)?;
+        let decrypted = match decoded.data {
+            Some(data) => data.plaintext,
+            _ => {
+                return Err(Error::Vault(format!(
+                    "No plaintext found in response: `{:#?}`",
+                    decoded
+                )))
+            }
+        };
+        let plaintext = base64::decode(&decrypted)?;
+        Ok(plaintext)
+    }
+
+    /// Encrypt many plaintexts in a single request via Transit secret
+    /// backend, keeping track of the key version used for each.
+    /// Ciphertexts are returned in the same order as `items`; if any item
+    /// fails, an error naming its index is returned.  If every item fails,
+    /// vault rejects the whole batch with `400 Bad Request` instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// let items = vec![b"one".to_vec(), b"two".to_vec()];
+    /// let res = client.transit_encrypt_batch(None, "keyname", &items);
+    /// assert_eq!(res.unwrap().len(), 2);
+    /// ```
+    pub fn transit_encrypt_batch<S: Into<String>>(
+        &self,
+        mountpoint: Option<String>,
+        key: S,
+        items: &[Vec<u8>],
+    ) -> Result<Vec<TransitCiphertext>> {
+        let input = items
+            .iter()
+            .map(|item| TransitBatchItem {
+                plaintext: Some(base64::encode(item)),
+                ..Default::default()
+            })
+            .collect();
+        self.transit_batch(mountpoint, "encrypt", key.into(), input)?
+            .into_iter()
+            .enumerate()
+            .map(|(i, result)| match result.ciphertext {
+                Some(ciphertext) => ciphertext.parse(),
+                None => Err(Error::Vault(format!(
+                    "No ciphertext found for batch item {}",
+                    i
+                ))),
+            })
+            .collect()
+    }
+
+    /// Decrypt many ciphertexts, produced by any version of the named key,
+    /// in a single request via Transit secret backend.  Plaintexts are
+    /// returned in the same order as `items`; if any item fails, an error
+    /// naming its index is returned.  If every item fails, vault rejects the
+    /// whole batch with `400 Bad Request` instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// let items = vec![b"one".to_vec(), b"two".to_vec()];
+    /// let encrypted = client.transit_encrypt_batch(None, "keyname", &items).unwrap();
+    /// let res = client.transit_decrypt_batch(None, "keyname", &encrypted);
+    /// assert_eq!(res.unwrap(), items);
+    /// ```
+    pub fn transit_decrypt_batch<S: Into<String>>(
+        &self,
+        mountpoint: Option<String>,
+        key: S,
+        items: &[TransitCiphertext],
+    ) -> Result<Vec<Vec<u8>>> {
+        let input = items
+            .iter()
+            .map(|item| TransitBatchItem {
+                ciphertext: Some(item.to_string()),
+                ..Default::default()
+            })
+            .collect();
+        self.transit_batch(mountpoint, "decrypt", key.into(), input)?
+            .into_iter()
+            .enumerate()
+            .map(|(i, result)| match result.plaintext {
+                Some(plaintext) => Ok(base64::decode(&plaintext)?),
+                None => Err(Error::Vault(format!(
+                    "No plaintext found for batch item {}",
+                    i
+                ))),
+            })
+            .collect()
+    }
+
+    fn transit_batch(
+        &self,
+        mountpoint: Option<String>,
+        operation: &str,
+        key: String,
+        batch_input: Vec<TransitBatchItem>,
+    ) -> Result<Vec<TransitBatchItem>> {
+        let path = mountpoint.unwrap_or_else(|| "transit".to_owned());
+        let expected = batch_input.len();
+        // report failed items in the results rather than failing the
+        // whole request, so the failed items can be named
+        let body = serde_json::to_string(&TransitBatchPayload {
+            batch_input,
+            partial_failure_response_code: 200,
+        })?;
+        let res = self.post::<_, String>(
+            &format!("/v1/{}/{}/{}", path, operation, key)[..],
+            Some(&body),
+            None,
+        )?;
+        let decoded: VaultResponse<TransitBatchData> = parse_vault_response(res)?;
+        let results = match decoded.data {
+            Some(data) => data.batch_results,
+            _ => {
+                return Err(Error::Vault(format!(
+                    "No batch results found in response: `{:#?}`",
+                    decoded
+                )))
+            }
+        };
+        if results.len() != expected {
+            return Err(Error::Vault(format!(
+                "Expected {} batch results, received {}",
+                expected,
+                results.len()
+            )));
+        }
+        for (i, result) in results.iter().enumerate() {
+            if let Some(ref error) = result.error {
+                if !error.is_empty() {
+                    return Err(Error::Vault(format!("Batch item {} failed: {}", i, error)));
+                }
+            }
+        }
+        Ok(results)
+    }
+
+    /// Rewrap a `vault:vN:`-formatted ciphertext with the latest version of
+    /// the named key via Transit secret backend, without exposing the
+    /// plaintext.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// let res = client.transit_rewrap(None, "keyname", "vault:v1:AmFmYWJjYlVk");
+    /// ```
+    pub fn transit_rewrap<S1: Into<String>, S2: AsRef<str>>(
+        &self,
+        mountpoint: Option<String>,
+        key: S1,
+        ciphertext: S2,
+    ) -> Result<TransitCiphertext> {
+        let path = mountpoint.unwrap_or_else(|| "transit".to_owned());
+        let body = serde_json::to_string(&TransitEncryptedData {
+            ciphertext: ciphertext.as_ref().to_owned(),
+        })?;
+        let res = self.post::<_, String>(
+            &format!("/v1/{}/rewrap/{}", path, key.into())[..],
+            Some(&body),
+            None,
+        )?;
+        let decoded: VaultResponse<TransitEncryptedData> = parse_vault_response(res)?;
+        match decoded.data {
+            Some(data) => data.ciphertext.parse(),
+            _ => Err(Error::Vault(format!(
+                "No ciphertext found in response: `{:#?}`",
+                decoded
+            ))),
+        }
+    }
+
+    /// Generate an HMAC of `input` with the named key via Transit secret
+    /// backend.  `algorithm` defaults to `sha2-256`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// let res = client.transit_hmac(None, "keyname", b"payload", None);
+    /// assert!(res.unwrap().starts_with("vault:v"));
+    /// ```
+    pub fn transit_hmac<S1: Into<String>, S2: AsRef<[u8]>>(
+        &self,
+        mountpoint: Option<String>,
+        key: S1,
+        input: S2,
+        algorithm: Option<&str>,
+    ) -> Result<String> {
+        let path = mountpoint.unwrap_or_else(|| "transit".to_owned());
+        let body = serde_json::to_string(&TransitHmacPayload {
+            input: base64::encode(input.as_ref()),
+            algorithm: algorithm.unwrap_or("sha2-256"),
+            hmac: None,
+        })?;
+        let res = self.post::<_, String>(
+            &format!("/v1/{}/hmac/{}", path, key.into())[..],
+            Some(&body),
+            None,
+        )?;
+        let decoded: VaultResponse<TransitHmacData> = parse_vault_response(res)?;
+        match decoded.data {
+            Some(data) => Ok(data.hmac),
+            _ => Err(Error::Vault(format!(
+                "No hmac found in response: `{:#?}`",
+                decoded
+            ))),
+        }
+    }
+
+    /// Verify an HMAC previously produced by `transit_hmac` for `input`.
+    /// `algorithm` defaults to `sha2-256` and must match the one used to
+    /// generate `hmac`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// let hmac = client.transit_hmac(None, "keyname", b"payload", None).unwrap();
+    /// let res = client.transit_verify_hmac(None, "keyname", b"payload", &hmac, None);
+    /// assert!(res.unwrap());
+    /// ```
+    pub fn transit_verify_hmac<S1: Into<String>, S2: AsRef<[u8]>, S3: AsRef<str>>(
+        &self,
+        mountpoint: Option<String>,
+        key: S1,
+        input: S2,
+        hmac: S3,
+        algorithm: Option<&str>,
+    ) -> Result<bool> {
+        let path = mountpoint.unwrap_or_else(|| "transit".to_owned());
+        let body = serde_json::to_string(&TransitHmacPayload {
+            input: base64::encode(input.as_ref()),
+            algorithm: algorithm.unwrap_or("sha2-256"),
+            hmac: Some(hmac.as_ref()),
+        })?;
+        let res = self.post::<_, String>(
+            &format!("/v1/{}/verify/{}", path, key.into())[..],
+            Some(&body),
+            None,
+        )?;
+        let decoded: VaultResponse<TransitVerifyData> = parse_vault_response(res)?;
+        match decoded.data {
+            Some(data) => Ok(data.valid),
+            _ => Err(Error::Vault(format!(
+                "No verification result found in response: `{:#?}`",
+                decoded
+            ))),
+        }
+    }
+
+    /// Generate `bytes` random bytes via Transit secret backend.  `format`
+    /// only controls how vault encodes them on the wire; the raw bytes are
+    /// returned either way.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::{client, Client};
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// let res = client.transit_generate_random(None, 32, client::RandomFormat::Hex);
+    /// assert_eq!(res.unwrap().len(), 32);
+    /// ```
+    pub fn transit_generate_random(
+        &self,
+        mountpoint: Option<String>,
+        bytes: usize,
+        format: RandomFormat,
+    ) -> Result<Vec<u8>> {
+        let path = mountpoint.unwrap_or_else(|| "transit".to_owned());
+        let body = serde_json::to_string(&TransitRandomPayload { format })?;
+        let res = self.post::<_, String>(
+            &format!("/v1/{}/random/{}", path, bytes)[..],
+            Some(&body),
+            None,
+        )?;
+        let decoded: VaultResponse<TransitRandomData> = parse_vault_response(res)?;
+        match decoded.data {
+            Some(data) => format.decode(&data.random_bytes),
+            _ => Err(Error::Vault(format!(
+                "No random bytes found in response: `{:#?}`",
+                decoded
+            ))),
+        }
+    }
+
+    /// Generate a new data key wrapped by the named key via Transit secret
+    /// backend.  When `plaintext` is true the raw key is returned as well, so
+    /// that it can be used to encrypt data locally; `bits` defaults to 256.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// let res = client.transit_generate_datakey(None, "keyname", true, None);
+    /// assert_eq!(res.unwrap().plaintext.unwrap().len(), 32);
+    /// ```
+    pub fn transit_generate_datakey<S: Into<String>>(
+        &self,
+        mountpoint: Option<String>,
+        key: S,
+        plaintext: bool,
+        bits: Option<u32>,
+    ) -> Result<DataKey> {
+        let path = mountpoint.unwrap_or_else(|| "transit".to_owned());
+        let key_type = if plaintext { "plaintext" } else { "wrapped" };
+        let body = serde_json::to_string(&TransitDataKeyPayload { bits })?;
+        let res = self.post::<_, String>(
+            &format!("/v1/{}/datakey/{}/{}", path, key_type, key.into())[..],
+            Some(&body),
+            None,
+        )?;
+        let decoded: VaultResponse<TransitDataKeyData> = parse_vault_response(res)?;
+        match decoded.data {
+            Some(data) => Ok(DataKey {
+                ciphertext: data.ciphertext,
+                plaintext: match data.plaintext {
+                    Some(ref encoded) if plaintext => Some(base64::decode(encoded)?),
+                    _ => None,
+                },
+            }),
+            _ => Err(Error::Vault(format!(
+                "No data key found in response: `{:#?}`",
+                decoded
+            ))),
+        }
+    }
+
+    /// Issue a certificate and private key for `common_name` from the PKI
+    /// secret engine, as allowed by `role`.  `mountpoint` defaults to `pki`.
+    ///
+    /// ```no_run
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::{client, Client};
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// let opts = client::PkiIssueOptions::default()
+    ///     .alt_names(vec!["www.example.com"])
+    ///     .ttl(client::VaultDuration::hours(1));
+    /// let cert = client.pki_issue(None, "example-dot-com", "example.com", &opts).unwrap();
+    /// println!("issued {}", cert.serial_number);
+    /// ```
+    pub fn pki_issue<S1: AsRef<str>, S2: AsRef<str>>(
+        &self,
+        mountpoint: Option<String>,
+        role: S1,
+        common_name: S2,
+        opts: &PkiIssueOptions,
+    ) -> Result<IssuedCert> {
+        let path = mountpoint.unwrap_or_else(|| "pki".to_owned());
+        let body = serde_json::to_string(&PkiIssuePayload {
+            common_name: common_name.as_ref(),
+            opts,
+        })?;
+        let res = self.post::<_, String>(
+            &format!("/v1/{}/issue/{}", path, role.as_ref())[..],
+            Some(&body),
+            None,
+        )?;
+        let decoded: VaultResponse<IssuedCert> = parse_vault_response(res)?;
+        match decoded.data {
+            Some(data) => Ok(data),
+            _ => Err(Error::Vault(format!(
+                "No certificate found in response: `{:#?}`",
+                decoded
+            ))),
+        }
+    }
+
+    /// Revoke the certificate with the given serial number, returning the
+    /// time it was revoked.  The serial number may be colon-delimited, as
+    /// returned by `pki_issue`, or plain hex.  `mountpoint` defaults to `pki`.
+    ///
+    /// ```no_run
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// let revoked = client.pki_revoke(None, "39dd2e90b7b0ebb1").unwrap();
+    /// println!("revoked at {}", revoked.0);
+    /// ```
+    pub fn pki_revoke<S: AsRef<str>>(
+        &self,
+        mountpoint: Option<String>,
+        serial_number: S,
+    ) -> Result<VaultNaiveDateTime> {
+        let path = mountpoint.unwrap_or_else(|| "pki".to_owned());
+        let body = serde_json::to_string(&PkiRevokePayload {
+            serial_number: normalize_serial_number(serial_number.as_ref()),
+        })?;
+        let res = self.post::<_, String>(&format!("/v1/{}/revoke", path)[..], Some(&body), None)?;
+        let decoded: VaultResponse<PkiRevocationData> = parse_vault_response(res)?;
+        match decoded.data {
+            Some(data) => Ok(data.revocation_time),
+            _ => Err(Error::Vault(format!(
+                "No revocation time found in response: `{:#?}`",
+                decoded
+            ))),
+        }
+    }
+
+    /// Fetch the PEM-encoded CA certificate of the PKI secret engine mounted
+    /// at `mountpoint`, which defaults to `pki`.
+    ///
+    /// ```no_run
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// let ca = client.pki_ca_pem(None).unwrap();
+    /// assert!(ca.starts_with("-----BEGIN CERTIFICATE-----"));
+    /// ```
+    pub fn pki_ca_pem(&self, mountpoint: Option<String>) -> Result<String> {
+        let path = mountpoint.unwrap_or_else(|| "pki".to_owned());
+        let res = self.get::<_, String>(&format!("/v1/{}/ca/pem", path)[..], None)?;
+        parse_text_response(res)
+    }
+
+    /// Fetch the PEM-encoded certificate revocation list of the PKI secret
+    /// engine mounted at `mountpoint`, which defaults to `pki`.
+    ///
+    /// ```no_run
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// let crl = client.pki_crl_pem(None).unwrap();
+    /// assert!(crl.starts_with("-----BEGIN X509 CRL-----"));
+    /// ```
+    pub fn pki_crl_pem(&self, mountpoint: Option<String>) -> Result<String> {
+        let path = mountpoint.unwrap_or_else(|| "pki".to_owned());
+        let res = self.get::<_, String>(&format!("/v1/{}/crl/pem", path)[..], None)?;
+        parse_text_response(res)
+    }
+
+    /// Generate the current code of the TOTP key `name`, stored in the TOTP
+    /// secret engine mounted at `mountpoint`, which defaults to `totp`.
+    ///
+    /// ```no_run
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// let code = client.totp_code(None, "my-key").unwrap();
+    /// assert!(client.totp_validate(None, "my-key", &code).unwrap());
+    /// ```
+    pub fn totp_code<S: AsRef<str>>(&self, mountpoint: Option<String>, name: S) -> Result<String> {
+        let path = mountpoint.unwrap_or_else(|| "totp".to_owned());
+        let res =
+            self.get::<_, String>(&format!("/v1/{}/code/{}", path, name.as_ref())[..], None)?;
+        let decoded: VaultResponse<TotpCodeData> = parse_vault_response(res)?;
+        match decoded.data {
+            Some(data) => Ok(data.code),
+            _ => Err(Error::Vault(format!(
+                "No code found in response: `{:#?}`",
+                decoded
+            ))),
+        }
+    }
+
+    /// Check whether `code` is currently valid for the TOTP key `name`.
+    /// `mountpoint` defaults to `totp`.
+    pub fn totp_validate<S1: AsRef<str>, S2: AsRef<str>>(
+        &self,
+        mountpoint: Option<String>,
+        name: S1,
+        code: S2,
+    ) -> Result<bool> {
+        let path = mountpoint.unwrap_or_else(|| "totp".to_owned());
+        let body = serde_json::to_string(&TotpValidatePayload {
+            code: code.as_ref(),
+        })?;
+        let res = self.post::<_, String>(
+            &format!("/v1/{}/code/{}", path, name.as_ref())[..],
+            Some(&body),
+            None,
+        )?;
+        let decoded: VaultResponse<TotpValidateData> = parse_vault_response(res)?;
+        match decoded.data {
+            Some(data) => Ok(data.valid),
+            _ => Err(Error::Vault(format!(
+                "No validation found in response: `{:#?}`",
+                decoded
+            ))),
+        }
+    }
+
+    /// Generate a one-time password to SSH to the host at `ip`, using the
+    /// OTP `role` of the SSH secret engine mounted at `mountpoint`, which
+    /// defaults to `ssh`.  `username` defaults to the role's default user.
+    ///
+    /// ```no_run
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// let otp = client.ssh_otp(None, "otp_key_role", "10.0.0.5", Some("ubuntu")).unwrap();
+    /// println!("ssh -p {} {}@{} with {}", otp.port, otp.username, otp.ip, otp.key);
+    /// ```
+    pub fn ssh_otp<S1: AsRef<str>, S2: AsRef<str>>(
+        &self,
+        mountpoint: Option<String>,
+        role: S1,
+        ip: S2,
+        username: Option<&str>,
+    ) -> Result<SshOtp> {
+        let path = mountpoint.unwrap_or_else(|| "ssh".to_owned());
+        let body = serde_json::to_string(&SshOtpPayload {
+            ip: ip.as_ref(),
+            username,
+        })?;
+        let res = self.post::<_, String>(
+            &format!("/v1/{}/creds/{}", path, role.as_ref())[..],
+            Some(&body),
+            None,
+        )?;
+        let decoded: VaultResponse<SshOtp> = parse_vault_response(res)?;
+        match decoded.data {
+            Some(data) => Ok(data),
+            _ => Err(Error::Vault(format!(
+                "No one-time password found in response: `{:#?}`",
+                decoded
+            ))),
+        }
+    }
+
+    /// Sign `public_key`, in OpenSSH format, using the CA `role` of the SSH
+    /// secret engine mounted at `mountpoint`, which defaults to `ssh`.
+    ///
+    /// ```no_run
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::{client, Client};
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// let public_key = std::fs::read_to_string("/home/me/.ssh/id_ed25519.pub").unwrap();
+    /// let opts = client::SshSignOptions::default()
+    ///     .valid_principals(vec!["me"])
+    ///     .ttl(client::VaultDuration::hours(8));
+    /// let signed = client.ssh_sign(None, "engineers", public_key, &opts).unwrap();
+    /// std::fs::write("/home/me/.ssh/id_ed25519-cert.pub", signed.signed_key).unwrap();
+    /// ```
+    pub fn ssh_sign<S1: AsRef<str>, S2: AsRef<str>>(
+        &self,
+        mountpoint: Option<String>,
+        role: S1,
+        public_key: S2,
+        opts: &SshSignOptions,
+    ) -> Result<SignedSshKey> {
+        let path = mountpoint.unwrap_or_else(|| "ssh".to_owned());
+        let body = serde_json::to_string(&SshSignPayload {
+            public_key: public_key.as_ref(),
+            opts,
+        })?;
+        let res = self.post::<_, String>(
+            &format!("/v1/{}/sign/{}", path, role.as_ref())[..],
+            Some(&body),
+            None,
+        )?;
+        let decoded: VaultResponse<SignedSshKey> = parse_vault_response(res)?;
+        match decoded.data {
+            Some(data) => Ok(data),
+            _ => Err(Error::Vault(format!(
+                "No signed key found in response: `{:#?}`",
+                decoded
+            ))),
+        }
+    }
+
+    /// Look up an identity entity by its id, e.g. the `entity_id` of a
+    /// token.  Corresponds to [`/identity/entity/id`][entity].
+    ///
+    /// ```no_run
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// let res = client.lookup_entity_by_id("8d6a45e5-572f-8f13-d226-cd0d1ec57297").unwrap();
+    /// println!("token belongs to {}", res.data.unwrap().name);
+    /// ```
+    ///
+    /// [entity]: https://www.vaultproject.io/api-docs/secret/identity/entity#read-entity-by-id
+    pub fn lookup_entity_by_id<S: AsRef<str>>(&self, id: S) -> Result<VaultResponse<EntityInfo>> {
+        let res =
+            self.get::<_, String>(&format!("/v1/identity/entity/id/{}", id.as_ref())[..], None)?;
+        parse_vault_response(res)
+    }
+
+    /// Look up an identity entity by its name.  Corresponds to
+    /// [`/identity/entity/name`][entity].
+    ///
+    /// [entity]: https://www.vaultproject.io/api-docs/secret/identity/entity#read-entity-by-name
+    pub fn lookup_entity_by_name<S: AsRef<str>>(
+        &self,
+        name: S,
+    ) -> Result<VaultResponse<EntityInfo>> {
+        let res = self.get::<_, String>(
+            &format!("/v1/identity/entity/name/{}", name.as_ref())[..],
+            None,
+        )?;
+        parse_vault_response(res)
+    }
+
+    /// Read an arbitrary `path`, e.g. of a secret engine this library does
+    /// not support, deserializing the response `data` into `D`.
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct MountTune {
+    ///     max_lease_ttl: u64,
+    /// }
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// let res = client.read::<MountTune>("sys/mounts/secret/tune").unwrap();
+    /// assert!(res.data.is_some());
+    /// ```
+    pub fn read<D: DeserializeOwned>(&self, path: &str) -> Result<VaultResponse<D>> {
+        let res = self.get::<_, String>(&format!("/v1/{}", path)[..], None)?;
+        parse_vault_response(res)
+    }
+
+    /// Read an arbitrary `path`, returning the raw response body.  Unlike
+    /// `read`, the body is neither validated as UTF-8 nor parsed, which
+    /// avoids copying large responses, e.g. bulk transit ciphertexts, more
+    /// than once.
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// let body = client.get_raw("sys/mounts/secret/tune").unwrap();
+    /// let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    /// assert!(json["data"].is_object());
+    /// ```
+    pub fn get_raw(&self, path: &str) -> Result<Vec<u8>> {
+        let mut res = self.get::<_, String>(&format!("/v1/{}", path)[..], None)?;
+        let mut body = Vec::new();
+        let _ = res.read_to_end(&mut body)?;
+        trace!("Response: {} bytes", body.len());
+        Ok(body)
+    }
+
+    /// Write `body` to an arbitrary `path`, deserializing the response
+    /// `data` into `D`.  Returns `None` when vault responds without a body,
+    /// as most writes do.
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    /// use std::collections::HashMap;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// let mut body = HashMap::new();
+    /// body.insert("max_lease_ttl", "48h");
+    /// let res = client.write::<_, ()>("sys/mounts/secret/tune", &body).unwrap();
+    /// assert!(res.is_none());
+    /// ```
+    pub fn write<B: Serialize, D: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<Option<VaultResponse<D>>> {
+        let body = serde_json::to_string(body)?;
+        let mut res = self.post::<_, String>(&format!("/v1/{}", path)[..], Some(&body), None)?;
+        match parse_endpoint_response(&mut res)? {
+            EndpointResponse::VaultResponse(res) => Ok(Some(res)),
+            EndpointResponse::Empty => Ok(None),
+        }
+    }
+
+    /// This function is an "escape hatch" of sorts to call any other vault api methods that
+    /// aren't directly supported in this library.
+    ///
+    /// Select the http verb you want, along with the endpoint, e.g. `auth/token/create`, along
+    /// with any wrapping or associated body text and the request will be sent.
+    ///
+    /// `wrap_ttl` may be built from a `VaultDuration`, a `Duration` or a
+    /// string such as `"15m"`, e.g. `Some(VaultDuration::minutes(15).into())`.
+    ///
+    /// See `it_can_perform_approle_workflow` test case for examples.
+    pub fn call_endpoint<D: DeserializeOwned>(
+        &self,
+        http_verb: HttpVerb,
+        endpoint: &str,
+        wrap_ttl: Option<WrapTtl>,
+        body: Option<&str>,
+    ) -> Result<EndpointResponse<D>> {
+        let url = format!("/v1/{}", endpoint);
+        let wrap_ttl = wrap_ttl.as_ref().map(WrapTtl::as_str);
+        match http_verb {
+            HttpVerb::GET => {
+                let mut res = self.get(&url, wrap_ttl)?;
+                parse_endpoint_response(&mut res)
+            }
+            HttpVerb::POST => {
+                let mut res = self.post(&url, body, wrap_ttl)?;
+                parse_endpoint_response(&mut res)
+            }
+            HttpVerb::PUT => {
+                let mut res = self.put(&url, body, wrap_ttl)?;
+                parse_endpoint_response(&mut res)
+            }
+            HttpVerb::DELETE => {
+                let mut res = self.delete(&url)?;
+                parse_endpoint_response(&mut res)
+            }
+            HttpVerb::LIST => {
+                let mut res = self.list(&url, body, wrap_ttl)?;
+                parse_endpoint_response(&mut res)
+            }
+        }
+    }
+
+    /// Accesses a given endpoint using the provided `wrap_ttl` and returns a single-use
+    /// `wrapping_token` to access the response provided by the endpoint.  `wrap_ttl` may be a
+    /// `VaultDuration`, a `Duration` or a string such as `"15m"`.
+    pub fn get_wrapping_token_for_endpoint<W: Into<WrapTtl>>(
+        &self,
+        http_verb: HttpVerb,
+        endpoint: &str,
+        wrap_ttl: W,
+        body: Option<&str>,
+    ) -> Result<String> {
+        let res = self.call_endpoint::<()>(http_verb, endpoint, Some(wrap_ttl.into()), body)?;
+        match res {
+            EndpointResponse::VaultResponse(res) => match res.wrap_info {
+                Some(wrap_info) => Ok(wrap_info.token),
+                _ => Err(Error::Vault(format!(
+                    "wrap_info is missing in response: {:?}",
+                    res
+                ))),
+            },
+            EndpointResponse::Empty => Err(Error::Vault("Received an empty response".to_string())),
+        }
+    }
+
+    ///
+    /// Deletes a saved secret
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    /// let res = client.set_secret("hello_delete", "world");
+    /// assert!(res.is_ok());
+    /// let res = client.delete_secret("hello_delete");
+    /// assert!(res.is_ok());
+    /// ```
+    pub fn delete_secret(&self, key: &str) -> Result<()> {
+        let _ = self.delete(&format!("/v1/{}/data/{}", self.secret_backend, key)[..])?;
+        Ok(())
+    }
+
+    /// Get postgresql secret backend
+    /// https://www.vaultproject.io/docs/secrets/postgresql/index.html
+    pub fn get_postgresql_backend(&self, name: &str) -> Result<VaultResponse<PostgresqlLogin>> {
+        self.database_creds(Some("postgresql".to_owned()), name)
+    }
+
+    /// Generate credentials for `role` from the database secret engine
+    /// mounted at `mountpoint`, which defaults to `database`.  The lease
+    /// information in the response can be used to renew or revoke the
+    /// credentials.
+    /// https://www.vaultproject.io/docs/secrets/databases
+    ///
+    /// ```no_run
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// let res = client.database_creds(None, "readonly").unwrap();
+    /// let creds = res.data.unwrap();
+    /// println!("connect as {}", creds.username);
+    /// ```
+    pub fn database_creds<S: AsRef<str>>(
+        &self,
+        mountpoint: Option<String>,
+        role: S,
+    ) -> Result<VaultResponse<DatabaseCreds>> {
+        let path = mountpoint.unwrap_or_else(|| "database".to_owned());
+        self.get_secret_engine_creds(&path, role.as_ref())
+    }
+
+    /// Get creds from an arbitrary backend
+    /// ```no_run
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    /// use serde::Deserialize;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// #[derive(Deserialize)]
+    /// struct PacketKey {
+    ///   api_key_token: String,
+    /// }
+    ///
+    /// let res = client.get_secret_engine_creds::<PacketKey>("packet", "1h-read-only-user").unwrap();
+    /// let api_token = res.data.unwrap().api_key_token;
+    /// ```
+    pub fn get_secret_engine_creds<K>(&self, backend: &str, name: &str) -> Result<VaultResponse<K>>
+    where
+        K: DeserializeOwned,
+    {
+        let res = self.get::<_, String>(&format!("/v1/{}/creds/{}", backend, name)[..], None)?;
+        let decoded: VaultResponse<K> = parse_vault_response(res)?;
+        Ok(decoded)
+    }
+
+    /// Generate a Nomad ACL token from the Nomad secret backend.  The lease
+    /// information in the response can be used to renew or revoke the token.
+    /// `mountpoint` defaults to `nomad`.
+    /// https://www.vaultproject.io/docs/secrets/nomad
+    ///
+    /// ```no_run
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// let res = client.get_nomad_token(None, "monitoring").unwrap();
+    /// let nomad_token = res.data.unwrap().secret_id;
+    /// ```
+    pub fn get_nomad_token<S: AsRef<str>>(
+        &self,
+        mountpoint: Option<String>,
+        role: S,
+    ) -> Result<VaultResponse<NomadCreds>> {
+        let path = mountpoint.unwrap_or_else(|| "nomad".to_owned());
+        self.get_secret_engine_creds(&path, role.as_ref())
+    }
 
-impl VaultClient<()> {
-    /// Construct a `VaultClient` via the `App ID`
-    /// [auth backend](https://www.vaultproject.io/docs/auth/app-id.html)
+    /// Generate AWS credentials for `role` from the AWS secret backend.  The
+    /// lease information in the response can be used to renew or revoke the
+    /// credentials.  `mountpoint` defaults to `aws`.
+    /// https://www.vaultproject.io/docs/secrets/aws
     ///
-    /// NOTE: This backend is now deprecated by vault.
-    #[deprecated(since = "0.6.1")]
-    pub fn new_app_id<U, S1: Into<String>, S2: Into<String>>(
-        host: U,
-        app_id: S1,
-        user_id: S2,
-    ) -> Result<VaultClient<()>>
-    where
-        U: TryInto<Url, Err = Error>,
-    {
-        let host = host.try_into()?;
-        let client = Client::new();
-        let payload = serde_json::to_string(&AppIdPayload {
-            app_id: app_id.into(),
-            user_id: user_id.into(),
-        })?;
-        let res = handle_reqwest_response(
-            client
-                .post(host.join("/v1/auth/app-id/login")?)
-                .body(payload)
-                .send(),
-        )?;
-        let decoded: VaultResponse<()> = parse_vault_response(res)?;
-        let token = match decoded.auth {
-            Some(ref auth) => auth.client_token.clone(),
-            None => {
-                return Err(Error::Vault(format!(
-                    "No client token found in response: `{:?}`",
-                    &decoded.auth
-                )))
+    /// ```no_run
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::{client, Client};
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// let res = client.aws_creds(None, "deploy", client::AwsCredsType::Sts).unwrap();
+    /// let creds = res.data.unwrap();
+    /// println!("AWS_ACCESS_KEY_ID={}", creds.access_key);
+    /// ```
+    pub fn aws_creds<S: AsRef<str>>(
+        &self,
+        mountpoint: Option<String>,
+        role: S,
+        creds_type: AwsCredsType,
+    ) -> Result<VaultResponse<AwsCreds>> {
+        let path = mountpoint.unwrap_or_else(|| "aws".to_owned());
+        match creds_type {
+            AwsCredsType::Iam => self.get_secret_engine_creds(&path, role.as_ref()),
+            AwsCredsType::Sts => {
+                let res = self
+                    .get::<_, String>(&format!("/v1/{}/sts/{}", path, role.as_ref())[..], None)?;
+                parse_vault_response(res)
             }
-        };
-        Ok(VaultClient {
-            host,
-            token,
-            client,
-            data: Some(decoded),
-            secret_backend: "secret".into(),
-        })
+        }
     }
 
-    /// Construct a `VaultClient` via the `AppRole`
-    /// [auth backend](https://www.vaultproject.io/docs/auth/approle.html)
-    pub fn new_app_role<U, R, S>(
-        host: U,
-        role_id: R,
-        secret_id: Option<S>,
-    ) -> Result<VaultClient<()>>
-    where
-        U: TryInto<Url, Err = Error>,
-        R: Into<String>,
-        S: Into<String>,
-    {
-        let host = host.try_into()?;
-        let client = Client::new();
-        let secret_id = match secret_id {
-            Some(s) => Some(s.into()),
-            None => None,
-        };
-        let payload = serde_json::to_string(&AppRolePayload {
-            role_id: role_id.into(),
-            secret_id,
-        })?;
-        let res = handle_reqwest_response(
-            client
-                .post(host.join("/v1/auth/approle/login")?)
-                .body(payload)
-                .send(),
-        )?;
-        let decoded: VaultResponse<()> = parse_vault_response(res)?;
-        let token = match decoded.auth {
-            Some(ref auth) => auth.client_token.clone(),
-            None => {
-                return Err(Error::Vault(format!(
-                    "No client token found in response: `{:?}`",
-                    &decoded.auth
-                )))
+    /// Get a list of policy names defined by this vault.  This requires
+    /// `root` privileges. Corresponds to [`/sys/policies/acl`][acl], falling
+    /// back to the legacy [`/sys/policy`][/sys/policy] endpoint for vault
+    /// servers which predate it.
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// let res = client.policies().unwrap();
+    /// assert!(res.contains(&"root".to_owned()));
+    /// ```
+    ///
+    /// [acl]: https://www.vaultproject.io/api-docs/system/policies
+    /// [/sys/policy]: https://www.vaultproject.io/docs/http/sys-policy.html
+    pub fn policies(&self) -> Result<Vec<String>> {
+        match self.list_acl_policies() {
+            Ok(policies) => Ok(policies),
+            Err(ref e) if is_unsupported_endpoint(e) => {
+                let res = self.get::<_, String>("/v1/sys/policy", None)?;
+                let decoded: PoliciesResponse = parse_vault_response(res)?;
+                Ok(decoded.policies)
             }
-        };
-        Ok(VaultClient {
-            host,
-            token,
-            client,
-            data: Some(decoded),
-            secret_backend: "secret".into(),
-        })
+            Err(e) => Err(e),
+        }
     }
 
-    /// Construct a `VaultClient` where no lookup is done through vault since it is assumed that the
-    /// provided token is a single-use token.
+    /// Get a list of ACL policy names.  Corresponds to
+    /// [`/sys/policies/acl`][acl].
     ///
-    /// A common use case for this method is when a `wrapping_token` has been received and you want
-    /// to query the `sys/wrapping/unwrap` endpoint.
-    pub fn new_no_lookup<U, S: Into<String>>(host: U, token: S) -> Result<VaultClient<()>>
-    where
-        U: TryInto<Url, Err = Error>,
-    {
-        let client = Client::new();
-        let host = host.try_into()?;
-        Ok(VaultClient {
-            host,
-            token: token.into(),
-            client,
-            data: None,
-            secret_backend: "secret".into(),
-        })
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// let res = client.list_acl_policies().unwrap();
+    /// assert!(res.contains(&"root".to_owned()));
+    /// ```
+    ///
+    /// [acl]: https://www.vaultproject.io/api-docs/system/policies
+    pub fn list_acl_policies(&self) -> Result<Vec<String>> {
+        let res = self.list::<_, String>("/v1/sys/policies/acl", None, None)?;
+        let decoded: VaultResponse<ListResponse> = parse_vault_response(res)?;
+        match decoded.data {
+            Some(data) => Ok(data.keys),
+            _ => Err(Error::Vault(format!(
+                "No policies found in response: `{:#?}`",
+                decoded
+            ))),
+        }
     }
-}
 
-impl<T> VaultClient<T>
-where
-    T: DeserializeOwned,
-{
-    /// Set the backend name to be used by this VaultClient
+    /// Read the rules of the named ACL policy.  Corresponds to
+    /// [`/sys/policies/acl/:name`][acl].
     ///
     /// ```
     /// # extern crate hashicorp_vault as vault;
@@ -833,15 +5968,28 @@ where
     ///
     /// let host = "http://127.0.0.1:8200";
     /// let token = "test12345";
-    /// let mut client = Client::new(host, token).unwrap();
-    /// client.secret_backend("my_secrets");
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// let rules = client.get_acl_policy("default").unwrap();
+    /// assert!(rules.contains("auth/token/lookup-self"));
     /// ```
-    pub fn secret_backend<S1: Into<String>>(&mut self, backend_name: S1) {
-        self.secret_backend = backend_name.into();
+    ///
+    /// [acl]: https://www.vaultproject.io/api-docs/system/policies
+    pub fn get_acl_policy<S: AsRef<str>>(&self, name: S) -> Result<String> {
+        let res =
+            self.get::<_, String>(&format!("/v1/sys/policies/acl/{}", name.as_ref())[..], None)?;
+        let decoded: VaultResponse<AclPolicyData> = parse_vault_response(res)?;
+        match decoded.data {
+            Some(data) => Ok(data.policy),
+            _ => Err(Error::Vault(format!(
+                "No policy found in response: `{:#?}`",
+                decoded
+            ))),
+        }
     }
-    /// Renew lease for `VaultClient`'s token and updates the
-    /// `self.data.auth` based upon the response.  Corresponds to
-    /// [`/auth/token/renew-self`][token].
+
+    /// Create or update the named ACL policy with the given HCL `rules`.
+    /// Corresponds to [`/sys/policies/acl/:name`][acl].
     ///
     /// ```
     /// # extern crate hashicorp_vault as vault;
@@ -849,25 +5997,185 @@ where
     ///
     /// let host = "http://127.0.0.1:8200";
     /// let token = "test12345";
-    /// let mut client = Client::new(host, token).unwrap();
+    /// let client = Client::new(host, token).unwrap();
     ///
-    /// client.renew().unwrap();
+    /// let rules = r#"path "secret/data/app/*" { capabilities = ["read"] }"#;
+    /// client.set_acl_policy("doc-acl-app", rules).unwrap();
+    /// assert_eq!(client.get_acl_policy("doc-acl-app").unwrap(), rules);
+    /// client.delete_acl_policy("doc-acl-app").unwrap();
     /// ```
     ///
-    /// [token]: https://www.vaultproject.io/docs/auth/token.html
-    pub fn renew(&mut self) -> Result<()> {
-        let res = self.post::<_, String>("/v1/auth/token/renew-self", None, None)?;
-        let vault_res: VaultResponse<T> = parse_vault_response(res)?;
-        if let Some(ref mut data) = self.data {
-            data.auth = vault_res.auth;
-        }
+    /// [acl]: https://www.vaultproject.io/api-docs/system/policies
+    pub fn set_acl_policy<S: AsRef<str>>(&self, name: S, rules: &str) -> Result<()> {
+        let body = serde_json::to_string(&PolicyPayload { policy: rules })?;
+        let _ = self.put::<_, String>(
+            &format!("/v1/sys/policies/acl/{}", name.as_ref())[..],
+            Some(&body),
+            None,
+        )?;
         Ok(())
     }
 
-    /// Renew the lease for the specified token.  Requires `root`
-    /// privileges.  Corresponds to [`/auth/token/renew[/token]`][token].
+    /// Delete the named ACL policy.  Corresponds to
+    /// [`/sys/policies/acl/:name`][acl].
+    ///
+    /// [acl]: https://www.vaultproject.io/api-docs/system/policies
+    pub fn delete_acl_policy<S: AsRef<str>>(&self, name: S) -> Result<()> {
+        let _ = self.delete(&format!("/v1/sys/policies/acl/{}", name.as_ref())[..])?;
+        Ok(())
+    }
+
+    /// Read the rules of the named policy.  Corresponds to
+    /// [`/sys/policies/acl/:name`][acl], as `get_acl_policy` does, falling
+    /// back to the legacy [`/sys/policy/:name`][/sys/policy] endpoint for
+    /// vault servers which predate it.
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// let rules = client.get_policy("default").unwrap();
+    /// assert!(rules.contains("auth/token/lookup-self"));
+    /// ```
+    ///
+    /// [acl]: https://www.vaultproject.io/api-docs/system/policies
+    /// [/sys/policy]: https://www.vaultproject.io/docs/http/sys-policy.html
+    pub fn get_policy<S: AsRef<str>>(&self, name: S) -> Result<String> {
+        let name = name.as_ref();
+        match self.get_acl_policy(name) {
+            Ok(rules) => Ok(rules),
+            Err(ref e) if is_unsupported_endpoint(e) => {
+                let res = self.get::<_, String>(&format!("/v1/sys/policy/{}", name)[..], None)?;
+                let decoded: PolicyResponse = parse_vault_response(res)?;
+                Ok(decoded.rules)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Create or update the named policy with the given HCL `rules`.
+    /// Corresponds to [`/sys/policies/acl/:name`][acl], as `set_acl_policy`
+    /// does, falling back to the legacy [`/sys/policy/:name`][/sys/policy]
+    /// endpoint for vault servers which predate it.
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// let rules = r#"path "secret/data/app/*" { capabilities = ["read"] }"#;
+    /// client.set_policy("doc-app", rules).unwrap();
+    /// assert_eq!(client.get_policy("doc-app").unwrap(), rules);
+    /// client.delete_policy("doc-app").unwrap();
+    /// ```
+    ///
+    /// [acl]: https://www.vaultproject.io/api-docs/system/policies
+    /// [/sys/policy]: https://www.vaultproject.io/docs/http/sys-policy.html
+    pub fn set_policy<S: AsRef<str>>(&self, name: S, rules: &str) -> Result<()> {
+        let name = name.as_ref();
+        match self.set_acl_policy(name, rules) {
+            Err(ref e) if is_unsupported_endpoint(e) => {
+                let body = serde_json::to_string(&PolicyPayload { policy: rules })?;
+                let _ = self.put::<_, String>(
+                    &format!("/v1/sys/policy/{}", name)[..],
+                    Some(&body),
+                    None,
+                )?;
+                Ok(())
+            }
+            res => res,
+        }
+    }
+
+    /// Delete the named policy.  Corresponds to
+    /// [`/sys/policies/acl/:name`][acl], as `delete_acl_policy` does,
+    /// falling back to the legacy [`/sys/policy/:name`][/sys/policy]
+    /// endpoint for vault servers which predate it.
+    ///
+    /// [acl]: https://www.vaultproject.io/api-docs/system/policies
+    /// [/sys/policy]: https://www.vaultproject.io/docs/http/sys-policy.html
+    pub fn delete_policy<S: AsRef<str>>(&self, name: S) -> Result<()> {
+        let name = name.as_ref();
+        match self.delete_acl_policy(name) {
+            Err(ref e) if is_unsupported_endpoint(e) => {
+                let _ = self.delete(&format!("/v1/sys/policy/{}", name)[..])?;
+                Ok(())
+            }
+            res => res,
+        }
+    }
+
+    /// Get the capabilities (e.g. `read`, `update` or `deny`) this client's
+    /// token has on `path`.  Corresponds to
+    /// [`/sys/capabilities-self`][capabilities-self].
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
+    ///
+    /// let res = client.capabilities_self("secret/data/hello").unwrap();
+    /// assert_eq!(res, vec!["root".to_owned()]);
+    /// ```
+    ///
+    /// [capabilities-self]: https://www.vaultproject.io/api-docs/system/capabilities-self
+    pub fn capabilities_self(&self, path: &str) -> Result<Vec<String>> {
+        self.capabilities_at("/v1/sys/capabilities-self", None, path)
+    }
+
+    /// Get the capabilities (e.g. `read`, `update` or `deny`) `token` has on
+    /// `path`.  Corresponds to [`/sys/capabilities`][capabilities].
+    ///
+    /// ```
+    /// # extern crate hashicorp_vault as vault;
+    /// # use vault::Client;
+    ///
+    /// let host = "http://127.0.0.1:8200";
+    /// let token = "test12345";
+    /// let client = Client::new(host, token).unwrap();
     ///
+    /// let res = client.capabilities(token, "secret/data/hello").unwrap();
+    /// assert_eq!(res, vec!["root".to_owned()]);
     /// ```
+    ///
+    /// [capabilities]: https://www.vaultproject.io/api-docs/system/capabilities
+    pub fn capabilities<S: AsRef<str>>(&self, token: S, path: &str) -> Result<Vec<String>> {
+        self.capabilities_at("/v1/sys/capabilities", Some(token.as_ref()), path)
+    }
+
+    fn capabilities_at(
+        &self,
+        endpoint: &str,
+        token: Option<&str>,
+        path: &str,
+    ) -> Result<Vec<String>> {
+        let body = serde_json::to_string(&CapabilitiesPayload { token, path })?;
+        let res = self.post::<_, String>(endpoint, Some(&body), None)?;
+        let decoded: VaultResponse<CapabilitiesData> = parse_vault_response(res)?;
+        match decoded.data {
+            Some(data) => Ok(data.capabilities),
+            _ => Err(Error::Vault(format!(
+                "No capabilities found in response: `{:#?}`",
+                decoded
+            ))),
+        }
+    }
+
+    /// Get CPU, memory and disk information about the host vault is
+    /// running on.  This requires `sudo` privileges.  Corresponds to
+    /// [`/sys/host-info`][host-info].
+    ///
+    /// ```no_run
     /// # extern crate hashicorp_vault as vault;
     /// # use vault::Client;
     ///
@@ -875,94 +6183,95 @@ where
     /// let token = "test12345";
     /// let client = Client::new(host, token).unwrap();
     ///
-    /// let token_to_renew = "test12345";
-    /// client.renew_token(token_to_renew, None).unwrap();
-    /// ```
+    /// let info = client.host_info().unwrap();
+    /// println!("{:?}", info.memory);
+    /// ```
+    ///
+    /// [host-info]: https://www.vaultproject.io/api-docs/system/host-info
+    pub fn host_info(&self) -> Result<HostInfo> {
+        let res = self.get::<_, String>("/v1/sys/host-info", None)?;
+        let decoded: VaultResponse<HostInfo> = parse_vault_response(res)?;
+        match decoded.data {
+            Some(data) => Ok(data),
+            _ => Err(Error::Vault(format!(
+                "No host information found in response: `{:#?}`",
+                decoded
+            ))),
+        }
+    }
+
+    /// Get a snapshot of the requests vault is currently serving, keyed by
+    /// request id.  Corresponds to [`/sys/in-flight-req`][in-flight-req].
+    ///
+    /// The shape of each entry varies between vault versions, so the raw
+    /// JSON is returned.
     ///
-    /// [token]: https://www.vaultproject.io/docs/auth/token.html
-    pub fn renew_token<S: Into<String>>(&self, token: S, increment: Option<u64>) -> Result<Auth> {
-        let body = serde_json::to_string(&RenewTokenOptions {
-            token: token.into(),
-            increment,
-        })?;
-        let res = self.post::<_, String>("/v1/auth/token/renew", Some(&body), None)?;
-        let vault_res: VaultResponse<()> = parse_vault_response(res)?;
-        vault_res
-            .auth
-            .ok_or_else(|| Error::Vault("No auth data returned while renewing token".to_owned()))
+    /// [in-flight-req]: https://www.vaultproject.io/api-docs/system/inflight-req
+    pub fn in_flight_requests(&self) -> Result<serde_json::Value> {
+        let res = self.get::<_, String>("/v1/sys/in-flight-req", None)?;
+        parse_vault_response(res)
     }
 
-    /// Revoke `VaultClient`'s token. This token can no longer be used.
-    /// Corresponds to [`/auth/token/revoke-self`][token].
+    /// Check the health of the vault server.  Corresponds to
+    /// [`/sys/health`][health].
+    ///
+    /// Vault reports standby, sealed and uninitialized servers with non-2xx
+    /// status codes; these are returned as a `HealthStatus` rather than an
+    /// error.
     ///
     /// ```
     /// # extern crate hashicorp_vault as vault;
-    /// # use vault::{client, Client};
+    /// # use vault::Client;
     ///
     /// let host = "http://127.0.0.1:8200";
     /// let token = "test12345";
     /// let client = Client::new(host, token).unwrap();
     ///
-    /// // Create a temporary token, and use it to create a new client.
-    /// let opts = client::TokenOptions::default()
-    ///   .ttl(client::VaultDuration::minutes(5));
-    /// let res = client.create_token(&opts).unwrap();
-    /// let mut new_client = Client::new(host, res.client_token).unwrap();
-    ///
-    /// // Issue and use a bunch of temporary dynamic credentials.
-    ///
-    /// // Revoke all our dynamic credentials with a single command.
-    /// new_client.revoke().unwrap();
+    /// let health = client.health().unwrap();
+    /// assert!(health.initialized);
+    /// assert!(!health.sealed);
     /// ```
     ///
-    /// Note that we consume our `self` parameter, so you cannot use the
-    /// client after revoking it.
-    ///
-    /// [token]: https://www.vaultproject.io/docs/auth/token.html
-    pub fn revoke(self) -> Result<()> {
-        let _ = self.post::<_, String>("/v1/auth/token/revoke-self", None, None)?;
-        Ok(())
+    /// [health]: https://www.vaultproject.io/api-docs/system/health
+    pub fn health(&self) -> Result<HealthStatus> {
+        let res = self
+            .build_request::<String>(Method::GET, "/v1/sys/health", &[], None, None)?
+            .send();
+        let res = match res {
+            // active, standby, DR secondary, performance standby,
+            // uninitialized and sealed respectively
+            Ok(res) if [200, 429, 472, 473, 501, 503].contains(&res.status().as_u16()) => res,
+            res => handle_reqwest_response(res)?,
+        };
+        parse_vault_response(res)
     }
 
-    /// Renew a specific lease that your token controls.  Corresponds to
-    /// [`/v1/sys/lease`][renew].
+    /// Find the active node of a high availability cluster.  Standby nodes
+    /// answer this too, so it can be used to send requests to the active
+    /// node directly.  Corresponds to [`/sys/leader`][leader].
     ///
-    /// ```no_run
+    /// ```
     /// # extern crate hashicorp_vault as vault;
     /// # use vault::Client;
-    /// use serde::Deserialize;
     ///
     /// let host = "http://127.0.0.1:8200";
     /// let token = "test12345";
     /// let client = Client::new(host, token).unwrap();
     ///
-    /// #[derive(Deserialize)]
-    /// struct PacketKey {
-    ///   api_key_token: String,
+    /// let leader = client.leader().unwrap();
+    /// if leader.ha_enabled && !leader.is_self {
+    ///     println!("active node is at {}", leader.leader_address);
     /// }
-    ///
-    /// let res = client.get_secret_engine_creds::<PacketKey>("packet", "1h-read-only-user").unwrap();
-    ///
-    /// client.renew_lease(res.lease_id.unwrap(), None).unwrap();
     /// ```
     ///
-    /// [renew]: https://www.vaultproject.io/docs/http/sys-renew.html
-    pub fn renew_lease<S: Into<String>>(
-        &self,
-        lease_id: S,
-        increment: Option<u64>,
-    ) -> Result<VaultResponse<()>> {
-        let body = serde_json::to_string(&RenewLeaseOptions {
-            lease_id: lease_id.into(),
-            increment,
-        })?;
-        let res = self.put::<_, String>("/v1/sys/leases/renew", Some(&body), None)?;
-        let vault_res: VaultResponse<()> = parse_vault_response(res)?;
-        Ok(vault_res)
+    /// [leader]: https://www.vaultproject.io/api-docs/system/leader
+    pub fn leader(&self) -> Result<LeaderStatus> {
+        let res = self.get::<_, String>("/v1/sys/leader", None)?;
+        parse_vault_response(res)
     }
 
-    /// Lookup token information for this client's token.  Corresponds to
-    /// [`/auth/token/lookup-self`][token].
+    /// Read the seal status of the vault server.  Corresponds to
+    /// [`/sys/seal-status`][seal-status].
     ///
     /// ```
     /// # extern crate hashicorp_vault as vault;
@@ -972,56 +6281,52 @@ where
     /// let token = "test12345";
     /// let client = Client::new(host, token).unwrap();
     ///
-    /// let res = client.lookup().unwrap();
-    /// assert!(res.data.unwrap().policies.len() >= 0);
+    /// let status = client.seal_status().unwrap();
+    /// assert!(!status.sealed);
     /// ```
     ///
-    /// [token]: https://www.vaultproject.io/docs/auth/token.html
-    pub fn lookup(&self) -> Result<VaultResponse<TokenData>> {
-        let res = self.get::<_, String>("/v1/auth/token/lookup-self", None)?;
-        let vault_res: VaultResponse<TokenData> = parse_vault_response(res)?;
-        Ok(vault_res)
+    /// [seal-status]: https://www.vaultproject.io/api-docs/system/seal-status
+    pub fn seal_status(&self) -> Result<SealStatus> {
+        let res = self.get::<_, String>("/v1/sys/seal-status", None)?;
+        parse_vault_response(res)
     }
 
-    /// Create a new vault token using the specified options.  Corresponds to
-    /// [`/auth/token/create`][token].
+    /// Provide one unseal key share, returning the seal status afterwards.
+    /// Vault is unsealed once enough shares have been provided.  This does
+    /// not require a valid token.  Corresponds to [`/sys/unseal`][unseal].
     ///
-    /// ```
+    /// ```no_run
     /// # extern crate hashicorp_vault as vault;
-    /// # use vault::{client, Client};
+    /// # use vault::Client;
     ///
     /// let host = "http://127.0.0.1:8200";
-    /// let token = "test12345";
-    /// let client = Client::new(host, token).unwrap();
-    ///
-    /// let opts = client::TokenOptions::default()
-    ///   .display_name("test_token")
-    ///   .policies(vec!("root"))
-    ///   .default_policy(false)
-    ///   .orphan(true)
-    ///   .renewable(false)
-    ///   .display_name("jdoe-temp")
-    ///   .number_of_uses(10)
-    ///   .ttl(client::VaultDuration::minutes(1))
-    ///   .explicit_max_ttl(client::VaultDuration::minutes(3));
-    /// let res = client.create_token(&opts).unwrap();
+    /// let client = Client::new_no_lookup(host, "").unwrap();
     ///
-    /// # let new_client = Client::new(host, res.client_token).unwrap();
-    /// # new_client.revoke().unwrap();
+    /// for key in &["key-share-1", "key-share-2", "key-share-3"] {
+    ///     if !client.unseal(key).unwrap().sealed {
+    ///         break;
+    ///     }
+    /// }
     /// ```
     ///
-    /// [token]: https://www.vaultproject.io/docs/auth/token.html
-    pub fn create_token(&self, opts: &TokenOptions) -> Result<Auth> {
-        let body = serde_json::to_string(opts)?;
-        let res = self.post::<_, String>("/v1/auth/token/create", Some(&body), None)?;
-        let vault_res: VaultResponse<()> = parse_vault_response(res)?;
-        vault_res
-            .auth
-            .ok_or_else(|| Error::Vault("Created token did not include auth data".into()))
+    /// [unseal]: https://www.vaultproject.io/api-docs/system/unseal
+    pub fn unseal(&self, key: &str) -> Result<SealStatus> {
+        let body = serde_json::to_string(&UnsealPayload { key })?;
+        let res = self.put::<_, String>("/v1/sys/unseal", Some(&body), None)?;
+        parse_vault_response(res)
     }
 
+    /// Seal the vault server.  This requires `sudo` privileges.
+    /// Corresponds to [`/sys/seal`][seal].
     ///
-    /// Saves a secret
+    /// [seal]: https://www.vaultproject.io/api-docs/system/seal
+    pub fn seal(&self) -> Result<()> {
+        let _ = self.post::<_, String>("/v1/sys/seal", None, None)?;
+        Ok(())
+    }
+
+    /// List the mounted secret engines, keyed by path.  Corresponds to
+    /// [`/sys/mounts`][mounts].
     ///
     /// ```
     /// # extern crate hashicorp_vault as vault;
@@ -1030,89 +6335,123 @@ where
     /// let host = "http://127.0.0.1:8200";
     /// let token = "test12345";
     /// let client = Client::new(host, token).unwrap();
-    /// let res = client.set_secret("hello_set", "world");
-    /// assert!(res.is_ok());
+    ///
+    /// let mounts = client.list_mounts().unwrap();
+    /// assert_eq!(mounts["secret/"].mount_type, "kv");
     /// ```
-    pub fn set_secret<S1: Into<String>, S2: AsRef<str>>(&self, key: S1, value: S2) -> Result<()> {
-        let secret = DefaultSecretType {
-            value: value.as_ref(),
-        };
-        self.set_custom_secret(key, &secret)
+    ///
+    /// [mounts]: https://www.vaultproject.io/api-docs/system/mounts
+    pub fn list_mounts(&self) -> Result<HashMap<String, MountInfo>> {
+        let res = self.get::<_, String>("/v1/sys/mounts", None)?;
+        let decoded: VaultResponse<HashMap<String, MountInfo>> = parse_vault_response(res)?;
+        match decoded.data {
+            Some(data) => Ok(data),
+            _ => Err(Error::Vault(format!(
+                "No mounts found in response: `{:#?}`",
+                decoded
+            ))),
+        }
     }
 
-    /// Saves a secret
+    /// Mount a secret engine of type `engine_type` at `path`.  Corresponds
+    /// to [`/sys/mounts/:path`][mounts].
     ///
     /// ```
     /// # extern crate hashicorp_vault as vault;
-    /// # use vault::Client;
-    /// use serde::{Deserialize, Serialize};
+    /// # use vault::{client, Client};
     ///
-    /// #[derive(Deserialize, Serialize)]
-    /// struct MyThing {
-    ///   awesome: String,
-    ///   thing: String,
-    /// }
     /// let host = "http://127.0.0.1:8200";
     /// let token = "test12345";
     /// let client = Client::new(host, token).unwrap();
-    /// let secret = MyThing {
-    ///   awesome: "I really am cool".into(),
-    ///   thing: "this is also in the secret".into(),
-    /// };
-    /// let res = client.set_custom_secret("hello_set", &secret);
-    /// assert!(res.is_ok());
+    ///
+    /// let config = client::MountConfig::default()
+    ///     .description("application secrets")
+    ///     .options(vec![("version", "2")]);
+    /// client.mount_secret_engine("doc-kv", "kv", &config).unwrap();
+    /// client.unmount("doc-kv").unwrap();
     /// ```
-    pub fn set_custom_secret<S1, S2>(&self, secret_name: S1, secret: &S2) -> Result<()>
-    where
-        S1: Into<String>,
-        S2: Serialize,
-    {
-        let secret = SecretContainer { data: secret };
-        let json = serde_json::to_string(&secret)?;
-        let _ = self.put::<_, String>(
-            &format!("/v1/{}/data/{}", self.secret_backend, secret_name.into())[..],
-            Some(&json),
+    ///
+    /// [mounts]: https://www.vaultproject.io/api-docs/system/mounts
+    pub fn mount_secret_engine<S1: AsRef<str>, S2: AsRef<str>>(
+        &self,
+        path: S1,
+        engine_type: S2,
+        config: &MountConfig,
+    ) -> Result<()> {
+        let body = serde_json::to_string(&MountPayload {
+            engine_type: engine_type.as_ref(),
+            description: config.description.as_ref(),
+            config: MountTtlPayload {
+                default_lease_ttl: config.default_lease_ttl.as_ref(),
+                max_lease_ttl: config.max_lease_ttl.as_ref(),
+            },
+            options: config.options.as_ref(),
+        })?;
+        let _ = self.post::<_, String>(
+            &format!("/v1/sys/mounts/{}", path.as_ref())[..],
+            Some(&body),
             None,
         )?;
         Ok(())
     }
 
+    /// Unmount the secret engine at `path`, revoking all of its secrets.
+    /// Corresponds to [`/sys/mounts/:path`][mounts].
     ///
-    /// List secrets at specified path
+    /// [mounts]: https://www.vaultproject.io/api-docs/system/mounts
+    pub fn unmount<S: AsRef<str>>(&self, path: S) -> Result<()> {
+        let _ = self.delete(&format!("/v1/sys/mounts/{}", path.as_ref())[..])?;
+        Ok(())
+    }
+
+    /// Change settings of the secret engine mounted at `path`, e.g. its
+    /// lease TTLs.  Corresponds to [`/sys/mounts/:path/tune`][tune].
     ///
     /// ```
     /// # extern crate hashicorp_vault as vault;
-    /// # use vault::Client;
+    /// # use vault::{client, Client};
     ///
     /// let host = "http://127.0.0.1:8200";
     /// let token = "test12345";
     /// let client = Client::new(host, token).unwrap();
-    /// let res = client.set_secret("hello/fred", "world");
-    /// assert!(res.is_ok());
-    /// let res = client.set_secret("hello/bob", "world");
-    /// assert!(res.is_ok());
-    /// let res = client.list_secrets("hello/");
-    /// assert!(res.is_ok());
-    /// assert_eq!(res.unwrap(), ["bob", "fred"]);
+    ///
+    /// let config = client::MountTuneConfig::default()
+    ///     .max_lease_ttl(client::VaultDuration::days(2));
+    /// client.tune_mount("secret", &config).unwrap();
+    /// let tune = client.read_mount_tune("secret").unwrap();
+    /// assert_eq!(tune.max_lease_ttl, client::VaultDuration::days(2));
     /// ```
-    pub fn list_secrets<S: AsRef<str>>(&self, key: S) -> Result<Vec<String>> {
-        let res = self.list::<_, String>(
-            &format!("/v1/{}/metadata/{}", self.secret_backend, key.as_ref())[..],
-            None,
+    ///
+    /// [tune]: https://www.vaultproject.io/api-docs/system/mounts#tune-mount-configuration
+    pub fn tune_mount<S: AsRef<str>>(&self, path: S, config: &MountTuneConfig) -> Result<()> {
+        let body = serde_json::to_string(config)?;
+        let _ = self.post::<_, String>(
+            &format!("/v1/sys/mounts/{}/tune", path.as_ref())[..],
+            Some(&body),
             None,
         )?;
-        let decoded: VaultResponse<ListResponse> = parse_vault_response(res)?;
+        Ok(())
+    }
+
+    /// Read the tuned settings of the secret engine mounted at `path`.
+    /// Corresponds to [`/sys/mounts/:path/tune`][tune].
+    ///
+    /// [tune]: https://www.vaultproject.io/api-docs/system/mounts#read-mount-configuration
+    pub fn read_mount_tune<S: AsRef<str>>(&self, path: S) -> Result<MountTuneInfo> {
+        let res =
+            self.get::<_, String>(&format!("/v1/sys/mounts/{}/tune", path.as_ref())[..], None)?;
+        let decoded: VaultResponse<MountTuneInfo> = parse_vault_response(res)?;
         match decoded.data {
-            Some(data) => Ok(data.keys),
+            Some(data) => Ok(data),
             _ => Err(Error::Vault(format!(
-                "No secrets found in response: `{:#?}`",
+                "No mount tuning found in response: `{:#?}`",
                 decoded
             ))),
         }
     }
 
-    ///
-    /// Fetches a saved secret
+    /// List the enabled auth methods, keyed by path.  Corresponds to
+    /// [`/sys/auth`][auth].
     ///
     /// ```
     /// # extern crate hashicorp_vault as vault;
@@ -1121,104 +6460,26 @@ where
     /// let host = "http://127.0.0.1:8200";
     /// let token = "test12345";
     /// let client = Client::new(host, token).unwrap();
-    /// let res = client.set_secret("hello_get", "world");
-    /// assert!(res.is_ok());
-    /// let res = client.get_secret("hello_get");
-    /// assert!(res.is_ok());
-    /// assert_eq!(res.unwrap(), "world");
-    /// ```
-    pub fn get_secret<S: AsRef<str>>(&self, key: S) -> Result<String> {
-        let secret: DefaultSecretType<String> = self.get_custom_secret(key)?;
-        Ok(secret.value)
-    }
-
-    ///
-    /// Fetches a saved secret
     ///
+    /// let methods = client.list_auth_methods().unwrap();
+    /// assert_eq!(methods["token/"].method_type, "token");
     /// ```
-    /// # extern crate hashicorp_vault as vault;
-    /// # use vault::Client;
-    /// use serde::{Deserialize, Serialize};
     ///
-    /// #[derive(Debug, Deserialize, Serialize)]
-    /// struct MyThing {
-    ///   awesome: String,
-    ///   thing: String,
-    /// }
-    /// let host = "http://127.0.0.1:8200";
-    /// let token = "test12345";
-    /// let client = Client::new(host, token).unwrap();
-    /// let secret = MyThing {
-    ///   awesome: "I really am cool".into(),
-    ///   thing: "this is also in the secret".into(),
-    /// };
-    /// let res1 = client.set_custom_secret("custom_secret", &secret);
-    /// assert!(res1.is_ok());
-    /// let res2: Result<MyThing, _> = client.get_custom_secret("custom_secret");
-    /// assert!(res2.is_ok());
-    /// let thing = res2.unwrap();
-    /// assert_eq!(thing.awesome, "I really am cool");
-    /// assert_eq!(thing.thing, "this is also in the secret");
-    /// ```
-    pub fn get_custom_secret<S: AsRef<str>, S2: DeserializeOwned + std::fmt::Debug>(
-        &self,
-        secret_name: S,
-    ) -> Result<S2> {
-        let res = self.get::<_, String>(
-            &format!("/v1/{}/data/{}", self.secret_backend, secret_name.as_ref())[..],
-            None,
-        )?;
-        let decoded: VaultResponse<SecretDataWrapper<S2>> = parse_vault_response(res)?;
+    /// [auth]: https://www.vaultproject.io/api-docs/system/auth
+    pub fn list_auth_methods(&self) -> Result<HashMap<String, AuthMethodInfo>> {
+        let res = self.get::<_, String>("/v1/sys/auth", None)?;
+        let decoded: VaultResponse<HashMap<String, AuthMethodInfo>> = parse_vault_response(res)?;
         match decoded.data {
-            Some(data) => Ok(data.data),
+            Some(data) => Ok(data),
             _ => Err(Error::Vault(format!(
-                "No secret found in response: `{:#?}`",
+                "No auth methods found in response: `{:#?}`",
                 decoded
             ))),
         }
     }
 
-    /// Fetch a wrapped secret. Token (one-time use) to fetch secret will be in `wrap_info.token`
-    /// https://www.vaultproject.io/docs/secrets/cubbyhole/index.html
-    pub fn get_secret_wrapped<S1: AsRef<str>, S2: AsRef<str>>(
-        &self,
-        key: S1,
-        wrap_ttl: S2,
-    ) -> Result<VaultResponse<()>> {
-        let res = self.get(
-            &format!("/v1/{}/data/{}", self.secret_backend, key.as_ref())[..],
-            Some(wrap_ttl.as_ref()),
-        )?;
-        parse_vault_response(res)
-    }
-
-    /// Using a vault client created from a wrapping token, fetch the unwrapped `VaultResponse` from
-    /// `sys/wrapping/unwrap`.
-    ///
-    /// The `data` attribute of `VaultResponse` should contain the unwrapped information, which is
-    /// returned as a `HashMap<String, String>`.
-    pub fn get_unwrapped_response(&self) -> Result<VaultResponse<HashMap<String, String>>> {
-        let res = self.post::<_, String>("/v1/sys/wrapping/unwrap", None, None)?;
-        let result: VaultResponse<SecretDataWrapper<HashMap<String, String>>> =
-            parse_vault_response(res)?;
-        Ok(result.into())
-    }
-
-    /// Reads the properties of an existing `AppRole`.
-    pub fn get_app_role_properties<S: AsRef<str>>(
-        &self,
-        role_name: S,
-    ) -> Result<VaultResponse<AppRoleProperties>> {
-        let res = self.get::<_, String>(
-            &format!("/v1/auth/approle/role/{}", role_name.as_ref()),
-            None,
-        )?;
-        parse_vault_response(res)
-    }
-
-    /// Encrypt a plaintext via Transit secret backend.
-    ///
-    /// # Example
+    /// Enable an auth method of type `method_type` at `path`.  Corresponds
+    /// to [`/sys/auth/:path`][auth].
     ///
     /// ```
     /// # extern crate hashicorp_vault as vault;
@@ -1227,145 +6488,127 @@ where
     /// let host = "http://127.0.0.1:8200";
     /// let token = "test12345";
     /// let client = Client::new(host, token).unwrap();
-    /// let res = client.transit_encrypt(None, "keyname", b"plaintext");
+    ///
+    /// client
+    ///     .enable_auth_method("doc-approle", "approle", Some("for services"))
+    ///     .unwrap();
+    /// client.disable_auth_method("doc-approle").unwrap();
     /// ```
-    pub fn transit_encrypt<S1: Into<String>, S2: AsRef<[u8]>>(
+    ///
+    /// [auth]: https://www.vaultproject.io/api-docs/system/auth
+    pub fn enable_auth_method<S1: AsRef<str>, S2: AsRef<str>>(
         &self,
-        mountpoint: Option<String>,
-        key: S1,
-        plaintext: S2,
-    ) -> Result<Vec<u8>> {
-        let path = mountpoint.unwrap_or_else(|| "transit".to_owned());
-        let encoded_plaintext = base64::encode(plaintext.as_ref());
-        let res = self.post::<_, String>(
-            &format!("/v1/{}/encrypt/{}", path, key.into())[..],
-            Some(&format!("{{\"plaintext\": \"{}\"}}", encoded_plaintext)[..]),
+        path: S1,
+        method_type: S2,
+        description: Option<&str>,
+    ) -> Result<()> {
+        let body = serde_json::to_string(&EnableAuthPayload {
+            method_type: method_type.as_ref(),
+            description,
+        })?;
+        let _ = self.post::<_, String>(
+            &format!("/v1/sys/auth/{}", path.as_ref())[..],
+            Some(&body),
             None,
         )?;
-        let decoded: VaultResponse<TransitEncryptedData> = parse_vault_response(res)?;
-        let payload = match decoded.data {
-            Some(data) => data.ciphertext,
-            _ => {
-                return Err(Error::Vault(format!(
-                    "No ciphertext found in response: `{:#?}`",
-                    decoded
-                )))
-            }
-        };
-        if !payload.starts_with("vault:v1:") {
-            return Err(Error::Vault(format!(
-                "Unrecognized ciphertext format: `{:#?}`",
-                payload
-            )));
-        };
-        let encoded_ciphertext = payload.trim_start_matches("vault:v1:");
-        let encrypted = base64::decode(encoded_ciphertext)?;
-        Ok(encrypted)
+        Ok(())
     }
 
-    /// Decrypt a ciphertext via Transit secret backend.
+    /// Disable the auth method at `path`, revoking all tokens it issued.
+    /// Corresponds to [`/sys/auth/:path`][auth].
     ///
-    /// # Example
+    /// [auth]: https://www.vaultproject.io/api-docs/system/auth
+    pub fn disable_auth_method<S: AsRef<str>>(&self, path: S) -> Result<()> {
+        let _ = self.delete(&format!("/v1/sys/auth/{}", path.as_ref())[..])?;
+        Ok(())
+    }
+
+    /// List the enabled audit devices, keyed by path.  Corresponds to
+    /// [`/sys/audit`][audit].
+    ///
+    /// [audit]: https://www.vaultproject.io/api-docs/system/audit
+    pub fn list_audit_devices(&self) -> Result<HashMap<String, AuditDeviceInfo>> {
+        let res = self.get::<_, String>("/v1/sys/audit", None)?;
+        let decoded: VaultResponse<HashMap<String, AuditDeviceInfo>> = parse_vault_response(res)?;
+        match decoded.data {
+            Some(data) => Ok(data),
+            _ => Err(Error::Vault(format!(
+                "No audit devices found in response: `{:#?}`",
+                decoded
+            ))),
+        }
+    }
+
+    /// Enable an audit device of type `device_type` at `path`, configured
+    /// with the device specific `options`.  Corresponds to
+    /// [`/sys/audit/:path`][audit].
     ///
     /// ```
     /// # extern crate hashicorp_vault as vault;
     /// # use vault::Client;
+    /// use std::collections::HashMap;
     ///
     /// let host = "http://127.0.0.1:8200";
     /// let token = "test12345";
     /// let client = Client::new(host, token).unwrap();
-    /// let res = client.transit_decrypt(None, "keyname", b"\x02af\x61bcb\x55d");
+    ///
+    /// let mut options = HashMap::new();
+    /// let _ = options.insert("file_path".to_owned(), "stdout".to_owned());
+    /// client
+    ///     .enable_audit_device("doc-audit", "file", options)
+    ///     .unwrap();
+    /// let devices = client.list_audit_devices().unwrap();
+    /// assert_eq!(devices["doc-audit/"].device_type, "file");
+    /// client.disable_audit_device("doc-audit").unwrap();
     /// ```
-    pub fn transit_decrypt<S1: Into<String>, S2: AsRef<[u8]>>(
+    ///
+    /// [audit]: https://www.vaultproject.io/api-docs/system/audit
+    pub fn enable_audit_device<S1: AsRef<str>, S2: AsRef<str>>(
         &self,
-        mountpoint: Option<String>,
-        key: S1,
-        ciphertext: S2,
-    ) -> Result<Vec<u8>> {
-        let path = mountpoint.unwrap_or_else(|| "transit".to_owned());
-        let encoded_ciphertext = "vault:v1:".to_owned() + &base64::encode(ciphertext.as_ref());
-        let res = self.post::<_, String>(
-            &format!("/v1/{}/decrypt/{}", path, key.into())[..],
-            Some(&format!("{{\"ciphertext\": \"{}\"}}", encoded_ciphertext)[..]),
+        path: S1,
+        device_type: S2,
+        options: HashMap<String, String>,
+    ) -> Result<()> {
+        let body = serde_json::to_string(&EnableAuditPayload {
+            device_type: device_type.as_ref(),
+            options: &options,
+        })?;
+        let _ = self.put::<_, String>(
+            &format!("/v1/sys/audit/{}", path.as_ref())[..],
+            Some(&body),
             None,
         )?;
-        let decoded: VaultResponse<TransitDecryptedData> = parse_vault_response(res)?;
-        let decrypted = match decoded.data {
-            Some(data) => data.plaintext,
-            _ => {
-                return Err(Error::Vault(format!(
-                    "No plaintext found in response: `{:#?}`",
-                    decoded
-                )))
-            }
-        };
-        let plaintext = base64::decode(&decrypted)?;
-        Ok(plaintext)
+        Ok(())
     }
 
-    /// This function is an "escape hatch" of sorts to call any other vault api methods that
-    /// aren't directly supported in this library.
-    ///
-    /// Select the http verb you want, along with the endpoint, e.g. `auth/token/create`, along
-    /// with any wrapping or associated body text and the request will be sent.
+    /// Disable the audit device at `path`.  Corresponds to
+    /// [`/sys/audit/:path`][audit].
     ///
-    /// See `it_can_perform_approle_workflow` test case for examples.
-    pub fn call_endpoint<D: DeserializeOwned>(
-        &self,
-        http_verb: HttpVerb,
-        endpoint: &str,
-        wrap_ttl: Option<&str>,
-        body: Option<&str>,
-    ) -> Result<EndpointResponse<D>> {
-        let url = format!("/v1/{}", endpoint);
-        match http_verb {
-            HttpVerb::GET => {
-                let mut res = self.get(&url, wrap_ttl)?;
-                parse_endpoint_response(&mut res)
-            }
-            HttpVerb::POST => {
-                let mut res = self.post(&url, body, wrap_ttl)?;
-                parse_endpoint_response(&mut res)
-            }
-            HttpVerb::PUT => {
-                let mut res = self.put(&url, body, wrap_ttl)?;
-                parse_endpoint_response(&mut res)
-            }
-            HttpVerb::DELETE => {
-                let mut res = self.delete(&url)?;
-                parse_endpoint_response(&mut res)
-            }
-            HttpVerb::LIST => {
-                let mut res = self.list(&url, body, wrap_ttl)?;
-                parse_endpoint_response(&mut res)
-            }
-        }
+    /// [audit]: https://www.vaultproject.io/api-docs/system/audit
+    pub fn disable_audit_device<S: AsRef<str>>(&self, path: S) -> Result<()> {
+        let _ = self.delete(&format!("/v1/sys/audit/{}", path.as_ref())[..])?;
+        Ok(())
     }
 
-    /// Accesses a given endpoint using the provided `wrap_ttl` and returns a single-use
-    /// `wrapping_token` to access the response provided by the endpoint.
-    pub fn get_wrapping_token_for_endpoint(
-        &self,
-        http_verb: HttpVerb,
-        endpoint: &str,
-        wrap_ttl: &str,
-        body: Option<&str>,
-    ) -> Result<String> {
-        let res = self.call_endpoint::<()>(http_verb, endpoint.as_ref(), Some(wrap_ttl), body)?;
-        match res {
-            EndpointResponse::VaultResponse(res) => match res.wrap_info {
-                Some(wrap_info) => Ok(wrap_info.token),
-                _ => Err(Error::Vault(format!(
-                    "wrap_info is missing in response: {:?}",
-                    res
-                ))),
-            },
-            EndpointResponse::Empty => Err(Error::Vault("Received an empty response".to_string())),
+    /// Get the status of disaster recovery and performance replication.
+    /// This is a Vault Enterprise feature.  Corresponds to
+    /// [`/sys/replication/status`][replication].
+    ///
+    /// [replication]: https://www.vaultproject.io/api-docs/system/replication
+    pub fn replication_status(&self) -> Result<ReplicationStatus> {
+        let res = self.get::<_, String>("/v1/sys/replication/status", None)?;
+        let decoded: VaultResponse<ReplicationStatus> = parse_vault_response(res)?;
+        match decoded.data {
+            Some(data) => Ok(data),
+            _ => Err(Error::Vault(format!(
+                "No replication status found in response: `{:#?}`",
+                decoded
+            ))),
         }
     }
 
-    ///
-    /// Deletes a saved secret
+    /// Read the status of the current root token generation attempt.
+    /// Corresponds to [`/sys/generate-root/attempt`][generate-root].
     ///
     /// ```
     /// # extern crate hashicorp_vault as vault;
@@ -1374,69 +6617,70 @@ where
     /// let host = "http://127.0.0.1:8200";
     /// let token = "test12345";
     /// let client = Client::new(host, token).unwrap();
-    /// let res = client.set_secret("hello_delete", "world");
-    /// assert!(res.is_ok());
-    /// let res = client.delete_secret("hello_delete");
-    /// assert!(res.is_ok());
+    ///
+    /// let status = client.generate_root_status().unwrap();
+    /// assert!(status.required > 0);
     /// ```
-    pub fn delete_secret(&self, key: &str) -> Result<()> {
-        let _ = self.delete(&format!("/v1/{}/data/{}", self.secret_backend, key)[..])?;
-        Ok(())
+    ///
+    /// [generate-root]: https://www.vaultproject.io/api-docs/system/generate-root
+    pub fn generate_root_status(&self) -> Result<GenerateRootStatus> {
+        let res = self.get::<_, String>("/v1/sys/generate-root/attempt", None)?;
+        parse_vault_response(res)
     }
 
-    /// Get postgresql secret backend
-    /// https://www.vaultproject.io/docs/secrets/postgresql/index.html
-    pub fn get_postgresql_backend(&self, name: &str) -> Result<VaultResponse<PostgresqlLogin>> {
-        self.get_secret_engine_creds("postgresql", name)
+    /// Start a root token generation attempt.  The returned status carries
+    /// the `nonce` to provide with each unseal key, and, unless `pgp_key` is
+    /// given, the `otp` needed to decode the final token.  Corresponds to
+    /// [`/sys/generate-root/attempt`][generate-root].
+    ///
+    /// [generate-root]: https://www.vaultproject.io/api-docs/system/generate-root
+    pub fn generate_root_init(&self, pgp_key: Option<&str>) -> Result<GenerateRootStatus> {
+        let body = serde_json::to_string(&GenerateRootInitPayload { pgp_key })?;
+        let res = self.put::<_, String>("/v1/sys/generate-root/attempt", Some(&body), None)?;
+        parse_vault_response(res)
     }
 
-    /// Get creds from an arbitrary backend
+    /// Provide a single unseal key for the root token generation attempt
+    /// identified by `nonce`.  Once enough keys have been provided, the
+    /// status' `state` is `Complete` with the encoded root token.
+    /// Corresponds to [`/sys/generate-root/update`][generate-root].
+    ///
     /// ```no_run
     /// # extern crate hashicorp_vault as vault;
     /// # use vault::Client;
-    /// use serde::Deserialize;
+    /// use vault::client::GenerateRootState;
     ///
     /// let host = "http://127.0.0.1:8200";
     /// let token = "test12345";
     /// let client = Client::new(host, token).unwrap();
     ///
-    /// #[derive(Deserialize)]
-    /// struct PacketKey {
-    ///   api_key_token: String,
+    /// let init = client.generate_root_init(None).unwrap();
+    /// let nonce = init.nonce.unwrap();
+    /// let otp = init.otp.unwrap();
+    /// for key in &["unseal-key-1", "unseal-key-2", "unseal-key-3"] {
+    ///     let status = client.generate_root_update(key, &nonce).unwrap();
+    ///     if let GenerateRootState::Complete { encoded_token } = status.state() {
+    ///         println!("decode {} with otp {}", encoded_token, otp);
+    ///         break;
+    ///     }
     /// }
-    ///
-    /// let res = client.get_secret_engine_creds::<PacketKey>("packet", "1h-read-only-user").unwrap();
-    /// let api_token = res.data.unwrap().api_key_token;
     /// ```
-    pub fn get_secret_engine_creds<K>(&self, backend: &str, name: &str) -> Result<VaultResponse<K>>
-    where
-        K: DeserializeOwned,
-    {
-        let res = self.get::<_, String>(&format!("/v1/{}/creds/{}", backend, name)[..], None)?;
-        let decoded: VaultResponse<K> = parse_vault_response(res)?;
-        Ok(decoded)
+    ///
+    /// [generate-root]: https://www.vaultproject.io/api-docs/system/generate-root
+    pub fn generate_root_update(&self, key: &str, nonce: &str) -> Result<GenerateRootStatus> {
+        let body = serde_json::to_string(&GenerateRootUpdatePayload { key, nonce })?;
+        let res = self.put::<_, String>("/v1/sys/generate-root/update", Some(&body), None)?;
+        parse_vault_response(res)
     }
 
-    /// Get a list of policy names defined by this vault.  This requires
-    /// `root` privileges. Corresponds to [`/sys/policy`][/sys/policy].
-    ///
-    /// ```
-    /// # extern crate hashicorp_vault as vault;
-    /// # use vault::Client;
-    ///
-    /// let host = "http://127.0.0.1:8200";
-    /// let token = "test12345";
-    /// let client = Client::new(host, token).unwrap();
-    ///
-    /// let res = client.policies().unwrap();
-    /// assert!(res.contains(&"root".to_owned()));
-    /// ```
+    /// Cancel the current root token generation attempt, discarding any
+    /// unseal keys provided so far.  Corresponds to
+    /// [`/sys/generate-root/attempt`][generate-root].
     ///
-    /// [/sys/policy]: https://www.vaultproject.io/docs/http/sys-policy.html
-    pub fn policies(&self) -> Result<Vec<String>> {
-        let res = self.get::<_, String>("/v1/sys/policy", None)?;
-        let decoded: PoliciesResponse = parse_vault_response(res)?;
-        Ok(decoded.policies)
+    /// [generate-root]: https://www.vaultproject.io/api-docs/system/generate-root
+    pub fn generate_root_cancel(&self) -> Result<()> {
+        let _ = self.delete("/v1/sys/generate-root/attempt")?;
+        Ok(())
     }
 
     fn get<S1: AsRef<str>, S2: Into<String>>(
@@ -1444,34 +6688,20 @@ where
         endpoint: S1,
         wrap_ttl: Option<S2>,
     ) -> Result<Response> {
-        let h = self.host.join(endpoint.as_ref())?;
-        match wrap_ttl {
-            Some(wrap_ttl) => Ok(handle_reqwest_response(
-                self.client
-                    .request(Method::GET, h)
-                    .header("X-Vault-Token", self.token.to_string())
-                    .header(CONTENT_TYPE, "application/json")
-                    .header("X-Vault-Wrap-TTL", wrap_ttl.into())
-                    .send(),
-            )?),
-            None => Ok(handle_reqwest_response(
-                self.client
-                    .request(Method::GET, h)
-                    .header("X-Vault-Token", self.token.to_string())
-                    .header(CONTENT_TYPE, "application/json")
-                    .send(),
-            )?),
-        }
+        self.get_with_query(endpoint, &[], wrap_ttl)
+    }
+
+    fn get_with_query<S1: AsRef<str>, S2: Into<String>>(
+        &self,
+        endpoint: S1,
+        query: &[(&str, &str)],
+        wrap_ttl: Option<S2>,
+    ) -> Result<Response> {
+        self.request(Method::GET, endpoint.as_ref(), query, None, wrap_ttl)
     }
 
     fn delete<S: AsRef<str>>(&self, endpoint: S) -> Result<Response> {
-        Ok(handle_reqwest_response(
-            self.client
-                .request(Method::DELETE, self.host.join(endpoint.as_ref())?)
-                .header("X-Vault-Token", self.token.to_string())
-                .header(CONTENT_TYPE, "application/json")
-                .send(),
-        )?)
+        self.request::<String>(Method::DELETE, endpoint.as_ref(), &[], None, None)
     }
 
     fn post<S1: AsRef<str>, S2: Into<String>>(
@@ -1480,31 +6710,8 @@ where
         body: Option<&str>,
         wrap_ttl: Option<S2>,
     ) -> Result<Response> {
-        let h = self.host.join(endpoint.as_ref())?;
-        let body = if let Some(body) = body {
-            body.to_string()
-        } else {
-            String::new()
-        };
-        match wrap_ttl {
-            Some(wrap_ttl) => Ok(handle_reqwest_response(
-                self.client
-                    .request(Method::POST, h)
-                    .header("X-Vault-Token", self.token.to_string())
-                    .header(CONTENT_TYPE, "application/json")
-                    .header("X-Vault-Wrap-TTL", wrap_ttl.into())
-                    .body(body)
-                    .send(),
-            )?),
-            None => Ok(handle_reqwest_response(
-                self.client
-                    .request(Method::POST, h)
-                    .header("X-Vault-Token", self.token.to_string())
-                    .header(CONTENT_TYPE, "application/json")
-                    .body(body)
-                    .send(),
-            )?),
-        }
+        let body = body.unwrap_or("");
+        self.request(Method::POST, endpoint.as_ref(), &[], Some(body), wrap_ttl)
     }
 
     fn put<S1: AsRef<str>, S2: Into<String>>(
@@ -1513,31 +6720,18 @@ where
         body: Option<&str>,
         wrap_ttl: Option<S2>,
     ) -> Result<Response> {
-        let h = self.host.join(endpoint.as_ref())?;
-        let body = if let Some(body) = body {
-            body.to_string()
-        } else {
-            String::new()
-        };
-        match wrap_ttl {
-            Some(wrap_ttl) => Ok(handle_reqwest_response(
-                self.client
-                    .request(Method::PUT, h)
-                    .header("X-Vault-Token", self.token.to_string())
-                    .header(CONTENT_TYPE, "application/json")
-                    .header("X-Vault-Wrap-TTL", wrap_ttl.into())
-                    .body(body)
-                    .send(),
-            )?),
-            None => Ok(handle_reqwest_response(
-                self.client
-                    .request(Method::PUT, h)
-                    .header("X-Vault-Token", self.token.to_string())
-                    .header(CONTENT_TYPE, "application/json")
-                    .body(body)
-                    .send(),
-            )?),
-        }
+        self.put_with_query(endpoint, &[], body, wrap_ttl)
+    }
+
+    fn put_with_query<S1: AsRef<str>, S2: Into<String>>(
+        &self,
+        endpoint: S1,
+        query: &[(&str, &str)],
+        body: Option<&str>,
+        wrap_ttl: Option<S2>,
+    ) -> Result<Response> {
+        let body = body.unwrap_or("");
+        self.request(Method::PUT, endpoint.as_ref(), query, Some(body), wrap_ttl)
     }
 
     fn list<S1: AsRef<str>, S2: Into<String>>(
@@ -1546,77 +6740,256 @@ where
         body: Option<&str>,
         wrap_ttl: Option<S2>,
     ) -> Result<Response> {
-        let h = self.host.join(endpoint.as_ref())?;
-        let body = if let Some(body) = body {
-            body.to_string()
-        } else {
-            String::new()
-        };
-        match wrap_ttl {
-            Some(wrap_ttl) => Ok(handle_reqwest_response(
-                self.client
-                    .request(
-                        Method::from_str("LIST".into()).expect("Failed to parse LIST to Method"),
-                        h,
-                    )
-                    .header("X-Vault-Token", self.token.to_string())
-                    .header(CONTENT_TYPE, "application/json")
-                    .header("X-Vault-Wrap-TTL", wrap_ttl.into())
-                    .body(body)
-                    .send(),
-            )?),
-            None => Ok(handle_reqwest_response(
-                self.client
-                    .request(
-                        Method::from_str("LIST".into()).expect("Failed to parse LIST to Method"),
-                        h,
-                    )
-                    .header("X-Vault-Token", self.token.to_string())
-                    .header(CONTENT_TYPE, "application/json")
-                    .body(body)
-                    .send(),
-            )?),
-        }
-    }
-}
-
-/// helper fn to check `Response` for success
+        self.list_with_query(endpoint, &[], body, wrap_ttl)
+    }
+
+    fn list_with_query<S1: AsRef<str>, S2: Into<String>>(
+        &self,
+        endpoint: S1,
+        query: &[(&str, &str)],
+        body: Option<&str>,
+        wrap_ttl: Option<S2>,
+    ) -> Result<Response> {
+        let method = Method::from_str("LIST").expect("Failed to parse LIST to Method");
+        self.request(method, endpoint.as_ref(), query, body, wrap_ttl)
+    }
+
+    /// Sends a request to vault, appending any `query` pairs to the url.
+    ///
+    /// `Content-Type` is only sent along with a body, as some proxies reject
+    /// bodyless requests which carry one.
+    ///
+    /// `GET` and `LIST` requests which fail with a 5xx are retried as
+    /// configured by `set_retry`.
+    ///
+    /// A standby's redirect to the active node is followed once, sending
+    /// the token along; a further redirect is returned as `Error::Standby`.
+    fn request<S: Into<String>>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        query: &[(&str, &str)],
+        body: Option<&str>,
+        wrap_ttl: Option<S>,
+    ) -> Result<Response> {
+        let wrap_ttl: Option<String> = wrap_ttl.map(Into::into);
+        let idempotent = method == Method::GET || method.as_str() == "LIST";
+        let mut attempt = 0;
+        loop {
+            let req =
+                self.build_request(method.clone(), endpoint, query, body, wrap_ttl.clone())?;
+            let mut res = req.send();
+            if let Some(location) = res.as_ref().ok().and_then(standby_redirect) {
+                debug!(
+                    "Following standby redirect of {} {} to {}",
+                    method, endpoint, location
+                );
+                let url = self.host.join(&location)?;
+                res = self
+                    .build_request_to(method.clone(), url, body, wrap_ttl.clone())
+                    .send();
+            }
+            let retry = match res {
+                Ok(ref res) => res.status().is_server_error(),
+                Err(_) => false,
+            };
+            if !(retry && idempotent && attempt < self.max_retries) {
+                return handle_reqwest_response(res);
+            }
+            let backoff = self.retry_backoff.saturating_mul(1 << attempt.min(16));
+            debug!(
+                "Retrying {} {} in {:?} after server error",
+                method, endpoint, backoff
+            );
+            std::thread::sleep(backoff);
+            attempt += 1;
+        }
+    }
+
+    fn build_request<S: Into<String>>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        query: &[(&str, &str)],
+        body: Option<&str>,
+        wrap_ttl: Option<S>,
+    ) -> Result<RequestBuilder> {
+        let mut url = endpoint_url(&self.host, &self.api_prefix, endpoint)?;
+        if !query.is_empty() {
+            let _ = url.query_pairs_mut().extend_pairs(query);
+        }
+        Ok(self.build_request_to(method, url, body, wrap_ttl))
+    }
+
+    fn build_request_to<S: Into<String>>(
+        &self,
+        method: Method,
+        url: Url,
+        body: Option<&str>,
+        wrap_ttl: Option<S>,
+    ) -> RequestBuilder {
+        let mut req = self
+            .client
+            .request(method, url)
+            .header("X-Vault-Token", self.token.to_string());
+        if let Some(ref namespace) = self.namespace {
+            req = req.header("X-Vault-Namespace", namespace.as_str());
+        }
+        if self.request_header {
+            req = req.header("X-Vault-Request", "true");
+        }
+        if let Some(timeout) = self.timeout {
+            req = req.timeout(timeout);
+        }
+        if let Some(wrap_ttl) = wrap_ttl {
+            req = req.header("X-Vault-Wrap-TTL", wrap_ttl.into());
+        }
+        if let Some(body) = body {
+            req = req
+                .header(CONTENT_TYPE, "application/json")
+                .body(body.to_string());
+        }
+        req
+    }
+}
+
+/// helper fn to build the `reqwest` clients this crate creates itself.
+/// They don't follow redirects, so that `VaultClient::request` can follow a
+/// standby's redirect to the active node itself.
+fn http_client_builder() -> ClientBuilder {
+    Client::builder().redirect(Policy::none())
+}
+
+/// helper fn to find the active node a standby redirected us to.  Clients
+/// passed in by the caller may follow such redirects themselves, in which
+/// case this never sees them.
+fn standby_redirect(res: &Response) -> Option<String> {
+    if !res.status().is_redirection() {
+        return None;
+    }
+    res.headers()
+        .get(LOCATION)
+        .and_then(|location| location.to_str().ok())
+        .map(|location| location.to_owned())
+}
+
+/// helper fn to convert a certificate serial number to the colon-delimited
+/// form vault expects, e.g. `39dd2e90` to `39:dd:2e:90`
+fn normalize_serial_number(serial_number: &str) -> String {
+    let hex: Vec<char> = serial_number
+        .chars()
+        .filter(|c| *c != ':' && *c != '-')
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    hex.chunks(2)
+        .map(|pair| pair.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+/// helper fn to check that a vault host is an http(s) URL, and normalize it
+/// to have no query or fragment and a path ending with `/`
+fn normalize_host(mut host: Url) -> Result<Url> {
+    if !matches!(host.scheme(), "http" | "https") || !host.has_host() {
+        return Err(Error::Vault(format!(
+            "Vault host `{}` must be an http or https URL, e.g. `https://vault.example.com:8200`",
+            host
+        )));
+    }
+    host.set_query(None);
+    host.set_fragment(None);
+    if !host.path().ends_with('/') {
+        let path = format!("{}/", host.path());
+        host.set_path(&path);
+    }
+    Ok(host)
+}
+
+/// helper fn to build the URL of an API endpoint.  The endpoint is joined
+/// relative to the host, rather than replacing its path, so a host behind a
+/// reverse proxy, e.g. `https://gateway/vault/`, keeps its path prefix.
+///
+/// Endpoints are written with the default `/v1` prefix, which is swapped
+/// for `api_prefix`.
+fn endpoint_url(host: &Url, api_prefix: &str, endpoint: &str) -> Result<Url> {
+    let endpoint = endpoint.trim_start_matches('/');
+    let endpoint = match endpoint.strip_prefix("v1/") {
+        Some(path) => format!("{}/{}", api_prefix.trim_matches('/'), path),
+        None => endpoint.to_owned(),
+    };
+    Ok(host.join(endpoint.trim_start_matches('/'))?)
+}
+
+/// helper fn to report a 404 when reading a secret as `Error::NotFound`
+fn secret_not_found(err: Error, key: &str) -> Error {
+    if err.is_not_found() {
+        Error::NotFound(key.to_owned())
+    } else {
+        err
+    }
+}
+
+/// helper fn to detect errors from vault servers which predate an endpoint,
+/// so that callers can fall back to a legacy endpoint
+fn is_unsupported_endpoint(err: &Error) -> bool {
+    matches!(
+        err.status(),
+        Some(StatusCode::NOT_FOUND) | Some(StatusCode::METHOD_NOT_ALLOWED)
+    )
+}
+
+/// helper fn to map the body of an unsuccessful response to an error, shared
+/// by the blocking and async clients.  Vault's structured errors are
+/// returned as the messages of an `Error::VaultApi`, any other body, e.g.
+/// from a proxy, as its single message.
+fn vault_api_error(status: u16, body: &[u8]) -> Error {
+    let errors = match serde_json::from_slice::<ErrorResponse>(body) {
+        Ok(decoded) => decoded.errors,
+        Err(_) if body.is_empty() => Vec::new(),
+        Err(_) => vec![String::from_utf8_lossy(body).into_owned()],
+    };
+    Error::VaultApi { status, errors }
+}
+
+/// helper fn to check `Response` for success.  An unsuccessful response is
+/// mapped by `vault_api_error`, unless its body can't be read.
 fn handle_reqwest_response(res: StdResult<Response, reqwest::Error>) -> Result<Response> {
     let mut res = res?;
     if res.status().is_success() {
         Ok(res)
+    } else if let Some(location) = standby_redirect(&res) {
+        Err(Error::Standby(location))
     } else {
-        let mut error_msg = String::new();
-        let _ = res.read_to_string(&mut error_msg).unwrap_or({
-            error_msg.push_str("Could not read vault response.");
-            0
-        });
-        Err(Error::VaultResponse(
-            format!(
-                "Vault request failed: {:?}, error message: `{}`",
-                res, error_msg
-            ),
-            res,
-        ))
+        let mut body = Vec::new();
+        if let Err(e) = res.read_to_end(&mut body) {
+            // keep the response, and so its status, even without a body
+            return Err(Error::VaultResponse(
+                format!(
+                    "Vault request failed: {:?}, error message could not be read: {}",
+                    res, e
+                ),
+                Box::new(res),
+            ));
+        }
+        Err(vault_api_error(res.status().as_u16(), &body))
     }
 }
 
 ///
 /// Parse a vault response manually
 ///
+/// The status of the response isn't checked, so do that first.  The
+/// client's own requests treat any non-2xx status as a failure: a standby
+/// redirect it couldn't follow becomes `Error::Standby`, otherwise the body
+/// is read and returned as `Error::VaultApi` along with the status, and
+/// only a body that can't be read gives `Error::VaultResponse`.
+///
 /// ```
 /// # extern crate hashicorp_vault as vault;
 /// # use vault::Client;
-/// use std::io::Read;
-/// use vault::{Error, Result, client::{VaultResponse, SecretDataWrapper}, TryInto};
-/// use std::result::Result as StdResult;
-/// use reqwest::{
-///    blocking::{Client as ReqwestClient, Response},
-///    header::CONTENT_TYPE,
-///    Method,
-///  };
+/// use vault::{Error, client::{VaultResponse, SecretDataWrapper}};
+/// use reqwest::blocking::Client as ReqwestClient;
 /// use serde::{Deserialize, Serialize};
-/// use url::Url;
 ///
 /// #[derive(Debug, Deserialize, Serialize)]
 /// struct MyThing {
@@ -1624,52 +6997,6 @@ fn handle_reqwest_response(res: StdResult<Response, reqwest::Error>) -> Result<R
 ///   thing: String,
 /// }
 ///
-/// fn handle_reqwest_response(res: StdResult<Response, reqwest::Error>) -> Result<Response> {
-///     let mut res = res?;
-///     if res.status().is_success() {
-///         Ok(res)
-///     } else {
-///         let mut error_msg = String::new();
-///         let _ = res.read_to_string(&mut error_msg).unwrap_or({
-///             error_msg.push_str("Could not read vault response.");
-///             0
-///         });
-///         Err(Error::VaultResponse(
-///             format!(
-///                 "Vault request failed: {:?}, error message: `{}`",
-///                 res, error_msg
-///             ),
-///             res,
-///         ))
-///     }
-/// }
-/// fn get<S1: AsRef<str>, S2: Into<String>, U: TryInto<Url, Err = Error>>(
-///     host: U,
-///     token: &str,
-///     endpoint: S1,
-///     wrap_ttl: Option<S2>,
-/// ) -> Result<Response> {
-/// let host = host.try_into()?;
-///     let h = host.join(endpoint.as_ref())?;
-///     let client = ReqwestClient::new();;
-///     match wrap_ttl {
-///         Some(wrap_ttl) => Ok(handle_reqwest_response(
-///             client
-///                 .request(Method::GET, h)
-///                 .header("X-Vault-Token", token.to_string())
-///                 .header(CONTENT_TYPE, "application/json")
-///                 .header("X-Vault-Wrap-TTL", wrap_ttl.into())
-///                 .send(),
-///         )?),
-///         None => Ok(handle_reqwest_response(
-///             client
-///                 .request(Method::GET, h)
-///                 .header("X-Vault-Token", token.to_string())
-///                 .header(CONTENT_TYPE, "application/json")
-///                 .send(),
-///         )?),
-///     }
-/// }
 /// let host = "http://127.0.0.1:8200";
 /// let token = "test12345";
 /// let client = Client::new(host, token).unwrap();
@@ -1679,12 +7006,12 @@ fn handle_reqwest_response(res: StdResult<Response, reqwest::Error>) -> Result<R
 /// };
 /// let res1 = client.set_custom_secret("custom_secret", &secret);
 /// assert!(res1.is_ok());
-/// let res = get::<&str, &str, &str>(
-///     host,
-///     token,
-///     "/v1/secret/data/custom_secret",
-///     None,
-/// ).unwrap();
+/// let res = ReqwestClient::new()
+///     .get(&format!("{}/v1/secret/data/custom_secret", host))
+///     .header("X-Vault-Token", token)
+///     .send()
+///     .unwrap();
+/// assert!(res.status().is_success());
 /// let decoded: VaultResponse<SecretDataWrapper<MyThing>> = vault::client::parse_vault_response(res).unwrap();
 /// let res2 = match decoded.data {
 ///     Some(data) => Ok(data.data),
@@ -1702,7 +7029,16 @@ where
     T: DeserializeOwned,
 {
     trace!("Response: {:?}", &res);
-    Ok(serde_json::from_reader(res)?)
+    // `from_reader` reads byte-by-byte, so buffer to avoid a syscall per byte
+    Ok(serde_json::from_reader(BufReader::new(res))?)
+}
+
+/// reads the body of a response which isn't wrapped in a `VaultResponse`,
+/// e.g. a PEM-encoded certificate
+fn parse_text_response(res: Response) -> Result<String> {
+    let body = res.text()?;
+    trace!("Response: {:?}", body);
+    Ok(body)
 }
 
 /// checks if response is empty before attempting to convert to a `VaultResponse`
@@ -1710,13 +7046,14 @@ fn parse_endpoint_response<T>(res: &mut Response) -> Result<EndpointResponse<T>>
 where
     T: DeserializeOwned,
 {
-    let mut body = String::new();
-    let _ = res.read_to_string(&mut body)?;
-    trace!("Response: {:?}", &body);
+    let mut body = Vec::new();
+    let _ = res.read_to_end(&mut body)?;
+    // the body may hold secrets, so only log its size
+    trace!("Response: {} bytes", body.len());
     if body.is_empty() {
         Ok(EndpointResponse::Empty)
     } else {
-        Ok(EndpointResponse::VaultResponse(serde_json::from_str(
+        Ok(EndpointResponse::VaultResponse(serde_json::from_slice(
             &body,
         )?))
     }
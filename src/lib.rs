@@ -79,14 +79,14 @@ impl TryFrom<Url> for Url {
     }
 }
 
-impl<'a> TryFrom<&'a Url> for Url {
+impl TryFrom<&Url> for Url {
     type Err = Error;
     fn try_from(u: &Url) -> ::std::result::Result<Self, Self::Err> {
         Ok(u.clone())
     }
 }
 
-impl<'a> TryFrom<&'a str> for Url {
+impl TryFrom<&str> for Url {
     type Err = Error;
     fn try_from(s: &str) -> ::std::result::Result<Self, Self::Err> {
         match Url::parse(s) {
@@ -96,7 +96,7 @@ impl<'a> TryFrom<&'a str> for Url {
     }
 }
 
-impl<'a> TryFrom<&'a String> for Url {
+impl TryFrom<&String> for Url {
     type Err = Error;
     fn try_from(s: &String) -> ::std::result::Result<Self, Self::Err> {
         match Url::parse(s) {
@@ -121,9 +121,10 @@ mod tests {
     use crate::client::HttpVerb::*;
     use crate::client::VaultClient as Client;
     use crate::client::{self, EndpointResponse};
-    use crate::Error;
+    use crate::{Error, TryInto};
     use reqwest::StatusCode;
     use serde::{Deserialize, Serialize};
+    use url::Url;
 
     /// vault host for testing
     const HOST: &str = "http://127.0.0.1:8200";
@@ -136,6 +137,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::needless_borrows_for_generic_args)]
     fn it_can_create_a_client_from_a_string_reference() {
         let _ = Client::new(&HOST.to_string(), TOKEN).unwrap();
     }
@@ -145,6 +147,195 @@ mod tests {
         let _ = Client::new(HOST.to_string(), TOKEN).unwrap();
     }
 
+    #[test]
+    fn it_can_create_a_client_from_a_url() {
+        let url = Url::parse(HOST).unwrap();
+        let _ = Client::new(&url, TOKEN).unwrap();
+        let _ = Client::new(url, TOKEN).unwrap();
+    }
+
+    #[test]
+    fn it_normalizes_the_host() {
+        let c = Client::new_no_lookup("http://vault.internal:8200/", TOKEN).unwrap();
+        assert_eq!(c.host.as_str(), "http://vault.internal:8200/");
+        let c = Client::new_no_lookup("http://vault.internal:8200", TOKEN).unwrap();
+        assert_eq!(c.host.as_str(), "http://vault.internal:8200/");
+        let c = Client::new_no_lookup("https://vault.example.com/prefix?x=1", TOKEN).unwrap();
+        assert_eq!(c.host.as_str(), "https://vault.example.com/prefix/");
+        assert!(Client::new_no_lookup("vault.internal:8200", TOKEN).is_err());
+    }
+
+    #[test]
+    fn it_keeps_the_host_path_prefix() {
+        // vault isn't served under `/prefix`, so the prefix must be kept for
+        // the token lookup to fail
+        match Client::new("http://127.0.0.1:8200/prefix", TOKEN) {
+            Err(e) => assert_eq!(e.status(), Some(StatusCode::NOT_FOUND), "{}", e),
+            Ok(_) => panic!("expected the path prefix to be requested"),
+        }
+    }
+
+    #[test]
+    fn it_can_override_the_api_prefix() {
+        // the prefix is moved into the host, so requests reach the same URLs
+        let c = client::VaultClientBuilder::new("http://127.0.0.1:8200/v1")
+            .unwrap()
+            .api_prefix("")
+            .token(TOKEN)
+            .unwrap();
+        c.set_secret("hello_api_prefix", "world").unwrap();
+        assert_eq!(c.get_secret("hello_api_prefix").unwrap(), "world");
+
+        match client::VaultClientBuilder::new(HOST)
+            .unwrap()
+            .api_prefix("/v2")
+            .token(TOKEN)
+        {
+            Err(e) => assert_eq!(e.status(), Some(StatusCode::NOT_FOUND), "{}", e),
+            Ok(_) => panic!("expected the custom prefix to be requested"),
+        }
+    }
+
+    #[test]
+    fn it_redacts_the_token_in_debug_output() {
+        let c = Client::new_no_lookup(HOST, TOKEN).unwrap();
+        assert_eq!(c.token(), TOKEN);
+        let debug = format!("{:?}", c);
+        assert!(!debug.contains(TOKEN));
+        assert!(debug.contains("127.0.0.1:8200"));
+    }
+
+    #[test]
+    fn it_redacts_secrets_in_debug_output() {
+        let res: client::VaultResponse<client::SecretData> = serde_json::from_str(
+            r#"{
+                "request_id": "1b4b4c8e-3f3a-4b4e-9b9b-2f6e3c1d1c1a",
+                "lease_id": null,
+                "renewable": false,
+                "lease_duration": 0,
+                "data": {"value": "hunter2"},
+                "warnings": null,
+                "auth": {
+                    "client_token": "s.leakedtoken",
+                    "accessor": "accessor",
+                    "policies": ["default"],
+                    "metadata": null,
+                    "lease_duration": 3600,
+                    "renewable": true
+                },
+                "wrap_info": null
+            }"#,
+        )
+        .unwrap();
+        let debug = format!("{:?}", res);
+        assert!(!debug.contains("hunter2"));
+        assert!(!debug.contains("s.leakedtoken"));
+        assert!(debug.contains("accessor"));
+
+        let creds: client::AwsCreds = serde_json::from_str(
+            r#"{"access_key": "AKIAEXAMPLE", "secret_key": "awssecret", "security_token": "awstoken"}"#,
+        )
+        .unwrap();
+        let debug = format!("{:?}", creds);
+        assert!(!debug.contains("awssecret"));
+        assert!(!debug.contains("awstoken"));
+        assert!(debug.contains("AKIAEXAMPLE"));
+
+        let cert: client::IssuedCert = serde_json::from_str(
+            r#"{"certificate": "CERT", "issuing_ca": "CA", "ca_chain": null,
+                "private_key": "PRIVATEKEY", "serial_number": "01:02"}"#,
+        )
+        .unwrap();
+        assert!(!format!("{:?}", cert).contains("PRIVATEKEY"));
+
+        let secret_id: client::SecretIdResponse = serde_json::from_str(
+            r#"{"secret_id": "roleSecret", "secret_id_accessor": "roleAccessor"}"#,
+        )
+        .unwrap();
+        let debug = format!("{:?}", secret_id);
+        assert!(!debug.contains("roleSecret"));
+        assert!(debug.contains("roleAccessor"));
+
+        let otp: client::SshOtp = serde_json::from_str(
+            r#"{"key": "sshOneTime", "key_type": "otp", "port": 22,
+                "username": "ubuntu", "ip": "10.0.0.1"}"#,
+        )
+        .unwrap();
+        assert!(!format!("{:?}", otp).contains("sshOneTime"));
+
+        let nomad: client::NomadCreds =
+            serde_json::from_str(r#"{"secret_id": "nomadSecret", "accessor_id": "nomadAccessor"}"#)
+                .unwrap();
+        let debug = format!("{:?}", nomad);
+        assert!(!debug.contains("nomadSecret"));
+        assert!(debug.contains("nomadAccessor"));
+
+        let wrap: client::WrapInfo = serde_json::from_str(
+            r#"{"ttl": 60, "token": "s.wrappingtoken",
+                "creation_time": "2021-01-01T00:00:00.000000000Z", "wrapped_accessor": null}"#,
+        )
+        .unwrap();
+        assert!(!format!("{:?}", wrap).contains("s.wrappingtoken"));
+
+        let status: client::GenerateRootStatus = serde_json::from_str(
+            r#"{"started": true, "nonce": "abc", "progress": 0, "required": 3,
+                "complete": false, "otp": "rootOneTime", "otp_length": 28}"#,
+        )
+        .unwrap();
+        let debug = format!("{:?}", status);
+        assert!(!debug.contains("rootOneTime"));
+        assert!(debug.contains("abc"));
+
+        let data_key = client::DataKey {
+            ciphertext: "vault:v1:wrappedKey".into(),
+            plaintext: Some(b"rawDataKey".to_vec()),
+        };
+        let debug = format!("{:?}", data_key);
+        assert!(!debug.contains("wrappedKey"));
+        assert!(!debug.contains(&format!("{:?}", b"rawDataKey".to_vec())));
+    }
+
+    #[test]
+    fn it_can_share_a_connection_pool() {
+        let first = client::VaultClientBuilder::new(HOST)
+            .unwrap()
+            .pool_max_idle_per_host(4)
+            .pool_idle_timeout(std::time::Duration::from_secs(30))
+            .token(TOKEN)
+            .unwrap();
+        let second = client::VaultClientBuilder::new(HOST)
+            .unwrap()
+            .client(first.http_client().clone())
+            .token(TOKEN)
+            .unwrap();
+        first.set_secret("hello_pool", "world").unwrap();
+        for _ in 0..10 {
+            assert_eq!(second.get_secret("hello_pool").unwrap(), "world");
+            assert_eq!(first.get_secret("hello_pool").unwrap(), "world");
+        }
+    }
+
+    #[test]
+    fn it_can_send_the_request_header() {
+        let client = client::VaultClientBuilder::new(HOST)
+            .unwrap()
+            .require_request_header(true)
+            .token(TOKEN)
+            .unwrap();
+        client.set_secret("hello_request_header", "world").unwrap();
+        assert_eq!(client.get_secret("hello_request_header").unwrap(), "world");
+    }
+
+    #[test]
+    fn it_can_set_and_clear_a_request_timeout() {
+        let mut client = Client::new(HOST, TOKEN).unwrap();
+        client.set_timeout(Some(std::time::Duration::from_nanos(1)));
+        assert!(client.get_secret("hello_timeout").is_err());
+        client.set_timeout(None);
+        client.set_secret("hello_timeout", "world").unwrap();
+        assert_eq!(client.get_secret("hello_timeout").unwrap(), "world");
+    }
+
     #[test]
     fn it_can_query_secrets() {
         let client = Client::new(HOST, TOKEN).unwrap();
@@ -154,6 +345,15 @@ mod tests {
         assert_eq!(res, "world");
     }
 
+    #[test]
+    fn it_can_read_raw_response_bodies() {
+        let client = Client::new(HOST, TOKEN).unwrap();
+        client.set_secret("hello_raw", "world").unwrap();
+        let body = client.get_raw("secret/data/hello_raw").unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["data"]["data"]["value"], "world");
+    }
+
     #[test]
     fn it_can_store_json_secrets() {
         let client = Client::new(HOST, TOKEN).unwrap();
@@ -168,7 +368,7 @@ mod tests {
     fn it_can_list_secrets() {
         let client = Client::new(HOST, TOKEN).unwrap();
 
-        let _res = client.set_secret("hello/fred", "world").unwrap();
+        client.set_secret("hello/fred", "world").unwrap();
         // assert!(res.is_ok());
         let res = client.set_secret("hello/bob", "world");
         assert!(res.is_ok());
@@ -189,10 +389,26 @@ mod tests {
         let res = client.list_secrets("non/existent/key");
         assert!(res.is_err());
 
-        if let Err(Error::VaultResponse(_, response)) = res {
-            assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        if let Err(Error::VaultApi { status, errors }) = res {
+            assert_eq!(status, StatusCode::NOT_FOUND.as_u16());
+            assert!(errors.is_empty());
         } else {
-            panic!("Error should match on VaultResponse with reqwest response.");
+            panic!("Error should match on VaultApi with the response status.");
+        }
+    }
+
+    #[test]
+    fn it_reports_missing_secrets_as_not_found() {
+        use std::collections::HashMap;
+        let client = Client::new(HOST, TOKEN).unwrap();
+
+        match client.get_secret("non/existent/key") {
+            Err(Error::NotFound(key)) => assert_eq!(key, "non/existent/key"),
+            res => panic!("expected NotFound, received: {:?}", res),
+        }
+        match client.get_secret_v2::<_, HashMap<String, String>>(None, "non/existent/key") {
+            Err(Error::NotFound(key)) => assert_eq!(key, "non/existent/key"),
+            res => panic!("expected NotFound, received: {:?}", res.map(|s| s.data)),
         }
     }
 
@@ -206,6 +422,16 @@ mod tests {
         assert_eq!(res, "world\n");
     }
 
+    #[test]
+    fn it_can_round_trip_secrets_with_special_characters() {
+        let client = Client::new(HOST, TOKEN).unwrap();
+
+        let value = "a\"b\\c\nd\u{0}\t";
+        client.set_secret("hello_special", value).unwrap();
+        let res = client.get_secret("hello_special").unwrap();
+        assert_eq!(res, value);
+    }
+
     #[test]
     fn it_returns_err_on_forbidden() {
         let client = Client::new(HOST, "test123456");
@@ -213,6 +439,68 @@ mod tests {
         assert!(client.is_err());
     }
 
+    #[test]
+    fn it_can_check_whether_the_token_is_valid() {
+        let client = Client::new(HOST, TOKEN).unwrap();
+        assert!(client.is_token_valid().unwrap());
+        let client = Client::new_no_lookup(HOST, "test123456").unwrap();
+        assert!(!client.is_token_valid().unwrap());
+    }
+
+    #[test]
+    fn it_can_read_kv_v2_secret_versions() {
+        let client = Client::new(HOST, TOKEN).unwrap();
+
+        let first = CustomSecretType {
+            name: "first".into(),
+        };
+        let second = CustomSecretType {
+            name: "second".into(),
+        };
+        let v1 = client.set_secret_v2(None, "versioned", &first).unwrap();
+        let v2 = client.set_secret_v2(None, "versioned", &second).unwrap();
+        assert_eq!(v2.version, v1.version + 1);
+
+        let res: client::KvV2Secret<CustomSecretType> =
+            client.get_secret_v2(None, "versioned").unwrap();
+        assert_eq!(res.data, second);
+        assert_eq!(res.metadata.version, v2.version);
+        let res: client::KvV2Secret<CustomSecretType> = client
+            .get_secret_version(None, "versioned", v1.version)
+            .unwrap();
+        assert_eq!(res.data, first);
+        assert!(res.metadata.deletion_time.is_none());
+    }
+
+    #[test]
+    fn it_can_destroy_secret_versions() {
+        use std::collections::HashMap;
+        let client = Client::new(HOST, TOKEN).unwrap();
+        let mut secret = HashMap::new();
+        let _ = secret.insert("value", "first");
+        let first = client
+            .set_secret_v2(None, "hello_destroy", &secret)
+            .unwrap();
+        let _ = secret.insert("value", "second");
+        let second = client
+            .set_secret_v2(None, "hello_destroy", &secret)
+            .unwrap();
+
+        client
+            .destroy_secret_versions(None, "hello_destroy", &[first.version])
+            .unwrap();
+        let res = client.get_secret_version::<_, HashMap<String, String>>(
+            None,
+            "hello_destroy",
+            first.version,
+        );
+        assert!(res.unwrap_err().is_not_found());
+        let res = client
+            .get_secret_version::<_, HashMap<String, String>>(None, "hello_destroy", second.version)
+            .unwrap();
+        assert_eq!(res.data["value"], "second");
+    }
+
     #[test]
     fn it_can_delete_a_secret() {
         let client = Client::new(HOST, TOKEN).unwrap();
@@ -280,6 +568,18 @@ mod tests {
         // now finally we can try to actually login!
         let _ = Client::new_app_role(HOST, &role_id[..], Some(&secret_id[..])).unwrap();
 
+        // copy the approle and bootstrap it via the typed helpers
+        let props = c
+            .get_app_role_properties("test_role")
+            .unwrap()
+            .data
+            .unwrap();
+        c.create_app_role("test_role_copy", &props).unwrap();
+        let role_id = c.read_role_id("test_role_copy").unwrap();
+        let secret = c.generate_secret_id("test_role_copy").unwrap();
+        assert!(!secret.secret_id_accessor.is_empty());
+        let _ = Client::new_app_role(HOST, role_id, Some(secret.secret_id)).unwrap();
+
         // clean up by disabling approle auth backend
         let res = c
             .call_endpoint(DELETE, "sys/auth/approle", None, None)
@@ -287,6 +587,96 @@ mod tests {
         panic_non_empty(&res);
     }
 
+    #[test]
+    fn it_parses_app_role_cidr_lists() {
+        let props: client::AppRoleProperties = serde_json::from_str(
+            r#"{
+                "bind_secret_id": true,
+                "local_secret_ids": false,
+                "secret_id_bound_cidrs": "10.0.0.0/8, 192.168.0.0/16",
+                "secret_id_num_uses": 0,
+                "secret_id_ttl": 600,
+                "token_bound_cidrs": ["127.0.0.1/32"],
+                "token_max_ttl": 1800,
+                "token_num_uses": 0,
+                "token_ttl": 1200,
+                "token_type": "default"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            props.secret_id_bound_cidrs,
+            Some(vec!["10.0.0.0/8".to_owned(), "192.168.0.0/16".to_owned()])
+        );
+        assert_eq!(
+            props.token_bound_cidrs,
+            Some(vec!["127.0.0.1/32".to_owned()])
+        );
+
+        let json = serde_json::to_value(&props).unwrap();
+        assert_eq!(json["secret_id_bound_cidrs"], "10.0.0.0/8,192.168.0.0/16");
+        assert_eq!(json["token_ttl"], 1200);
+    }
+
+    #[test]
+    fn it_parses_app_roles_of_older_vault_versions() {
+        let props: client::AppRoleProperties = serde_json::from_str(
+            r#"{
+                "bind_secret_id": true,
+                "bound_cidr_list": "",
+                "period": 0,
+                "policies": ["default"],
+                "secret_id_num_uses": 0,
+                "secret_id_ttl": 600,
+                "token_max_ttl": 1800,
+                "token_num_uses": 0,
+                "token_ttl": 1200
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(props.local_secret_ids, None);
+        assert!(props.token_type.is_none());
+
+        let json = serde_json::to_value(&props).unwrap();
+        assert!(json.get("local_secret_ids").is_none());
+        assert!(json.get("token_type").is_none());
+    }
+
+    #[test]
+    fn it_can_login_with_userpass() {
+        let c = Client::new(HOST, TOKEN).unwrap();
+        let _: EndpointResponse<()> = c
+            .call_endpoint(DELETE, "sys/auth/userpass", None, None)
+            .unwrap();
+        let res: EndpointResponse<()> = c
+            .call_endpoint(
+                POST,
+                "sys/auth/userpass",
+                None,
+                Some("{\"type\":\"userpass\"}"),
+            )
+            .unwrap();
+        panic_non_empty(&res);
+        let res: EndpointResponse<()> = c
+            .call_endpoint(
+                POST,
+                "auth/userpass/users/test_user",
+                None,
+                Some("{\"password\":\"test_password\",\"policies\":\"default\"}"),
+            )
+            .unwrap();
+        panic_non_empty(&res);
+
+        let user_client = Client::new_userpass(HOST, "test_user", "test_password", None).unwrap();
+        let auth = user_client.data.unwrap().auth.unwrap();
+        assert!(auth.policies.contains(&"default".to_string()));
+
+        let res: EndpointResponse<()> = c
+            .call_endpoint(DELETE, "sys/auth/userpass", None, None)
+            .unwrap();
+        panic_non_empty(&res);
+    }
+
     #[test]
     fn it_can_read_a_wrapped_secret() {
         let client = Client::new(HOST, TOKEN).unwrap();
@@ -295,11 +685,37 @@ mod tests {
         // wrap the secret's value in `sys/wrapping/unwrap` with a TTL of 2 minutes
         let res = client.get_secret_wrapped("hello_delete_2", "2m").unwrap();
         let wrapping_token = res.wrap_info.unwrap().token;
+        // inspect the wrapping token, then swap it for a fresh one
+        let lookup = client.wrapping_lookup(&wrapping_token).unwrap();
+        assert_eq!(lookup.creation_ttl.0.as_secs(), 120);
+        let wrapping_token = client.wrapping_rewrap(&wrapping_token).unwrap().token;
         // make a new client with the wrapping token
         let c2 = Client::new_no_lookup(HOST, wrapping_token).unwrap();
         // read the cubbyhole response (can only do this once!)
         let res = c2.get_unwrapped_response().unwrap();
         assert_eq!(res.data.unwrap()["value"], "second world");
+
+        // typed TTLs are sent in seconds
+        let res = client
+            .get_secret_wrapped("hello_delete_2", client::VaultDuration::minutes(3))
+            .unwrap();
+        let wrapping_token = res.wrap_info.unwrap().token;
+        let lookup = client.wrapping_lookup(&wrapping_token).unwrap();
+        assert_eq!(lookup.creation_ttl.0.as_secs(), 180);
+        let res: EndpointResponse<()> = client
+            .call_endpoint(
+                GET,
+                "secret/data/hello_delete_2",
+                Some(client::VaultDuration::minutes(4).into()),
+                None,
+            )
+            .unwrap();
+        let wrapping_token = match res {
+            EndpointResponse::VaultResponse(res) => res.wrap_info.unwrap().token,
+            EndpointResponse::Empty => panic!("expected a wrapped response"),
+        };
+        let lookup = client.wrapping_lookup(&wrapping_token).unwrap();
+        assert_eq!(lookup.creation_ttl.0.as_secs(), 240);
     }
 
     #[test]
@@ -318,15 +734,12 @@ mod tests {
         panic_non_empty(&res);
         let client_policies = c.policies().unwrap();
         let expected_policies = ["default", "test_policy_1", "test_policy_2", "root"];
-        let _ = expected_policies
-            .iter()
-            .map(|p| {
-                assert!(client_policies.contains(&(*p).to_string()));
-            })
-            .last();
+        for p in expected_policies.iter() {
+            assert!(client_policies.contains(&(*p).to_string()));
+        }
         let token_name = "policy_test_token".to_string();
         let token_opts = client::TokenOptions::default()
-            .policies(vec!["test_policy_1", "test_policy_2"].into_iter())
+            .policies(vec!["test_policy_1", "test_policy_2"])
             .default_policy(false)
             .id(&token_name[..])
             .ttl(client::VaultDuration::minutes(1));
@@ -355,6 +768,30 @@ mod tests {
         panic_non_empty(&res);
     }
 
+    #[test]
+    fn it_can_create_tokens_with_metadata() {
+        let c = Client::new(HOST, TOKEN).unwrap();
+        let token_opts = client::TokenOptions::default()
+            .meta(vec![("service", "billing")])
+            .ttl(client::VaultDuration::minutes(1));
+        let res = c.create_token(&token_opts).unwrap();
+        let new_client = Client::new(HOST, res.client_token).unwrap();
+        let meta = new_client.lookup().unwrap().data.unwrap().meta.unwrap();
+        assert_eq!(meta["service"], "billing");
+    }
+
+    #[test]
+    fn it_can_revoke_tokens_by_accessor() {
+        let c = Client::new(HOST, TOKEN).unwrap();
+        let res = c
+            .create_token(&client::TokenOptions::default().ttl(client::VaultDuration::minutes(1)))
+            .unwrap();
+        let accessor = res.accessor.unwrap();
+        assert!(c.lookup_accessor(&accessor).is_ok());
+        c.revoke_accessor(&accessor).unwrap();
+        assert!(c.lookup_accessor(&accessor).is_err());
+    }
+
     #[test]
     fn it_can_list_things() {
         let c = Client::new(HOST, TOKEN).unwrap();
@@ -374,6 +811,178 @@ mod tests {
     }
 
     #[test]
+    fn it_refuses_to_renew_non_renewable_tokens_in_strict_mode() {
+        let c = Client::new(HOST, TOKEN).unwrap();
+        let opts = client::TokenOptions::default()
+            .renewable(false)
+            .ttl(client::VaultDuration::minutes(1));
+        let res = c.create_token(&opts).unwrap();
+        let mut new_client = Client::new(HOST, res.client_token).unwrap();
+        new_client.strict_renewal(true);
+        match new_client.renew() {
+            Err(Error::NotRenewable(_)) => {}
+            other => panic!("expected NotRenewable error, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_checks_the_cached_token_data_in_strict_mode() {
+        let c = Client::new(HOST, TOKEN).unwrap();
+        let opts = client::TokenOptions::default()
+            .renewable(false)
+            .ttl(client::VaultDuration::minutes(1));
+        let res = c.create_token(&opts).unwrap();
+        let mut new_client = Client::new(HOST, &res.client_token).unwrap();
+        new_client.strict_renewal(true);
+        // a lookup would now fail, so the cached token data must be used
+        c.revoke_token(&res.client_token).unwrap();
+        match new_client.renew() {
+            Err(Error::NotRenewable(_)) => {}
+            other => panic!("expected NotRenewable error, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_parses_unset_root_generation_fields_as_none() {
+        use crate::client::GenerateRootState;
+
+        let status: client::GenerateRootStatus = serde_json::from_str(
+            r#"{"started": false, "nonce": "", "progress": 0, "required": 3,
+                "complete": false, "encoded_token": "", "encoded_root_token": "",
+                "pgp_fingerprint": "", "otp": "", "otp_length": 28}"#,
+        )
+        .unwrap();
+        assert!(status.nonce.is_none());
+        assert!(status.otp.is_none());
+        assert!(status.encoded_token.is_none());
+        assert!(status.pgp_fingerprint.is_none());
+        assert_eq!(status.state(), GenerateRootState::NotStarted);
+
+        let status: client::GenerateRootStatus = serde_json::from_str(
+            r#"{"started": true, "nonce": "abc", "progress": 3, "required": 3,
+                "complete": true, "encoded_root_token": "ZW5jb2RlZA=="}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            status.state(),
+            GenerateRootState::Complete {
+                encoded_token: "ZW5jb2RlZA==".into()
+            }
+        );
+    }
+
+    #[test]
+    fn it_can_start_and_cancel_root_generation() {
+        use crate::client::GenerateRootState;
+
+        let c = Client::new(HOST, TOKEN).unwrap();
+        let init = c.generate_root_init(None).unwrap();
+        assert!(init.otp.is_some());
+        assert!(init.pgp_fingerprint.is_none());
+        match c.generate_root_status().unwrap().state() {
+            GenerateRootState::InProgress { nonce, .. } => assert_eq!(Some(nonce), init.nonce),
+            state => panic!("expected attempt in progress, got: {:?}", state),
+        }
+        c.generate_root_cancel().unwrap();
+        assert_eq!(
+            c.generate_root_status().unwrap().state(),
+            GenerateRootState::NotStarted
+        );
+    }
+
+    #[test]
+    fn it_can_read_the_leader_status() {
+        let client = Client::new(HOST, TOKEN).unwrap();
+        let leader = client.leader().unwrap();
+        if leader.ha_enabled {
+            assert!(!leader.leader_address.is_empty());
+        } else {
+            assert!(!leader.is_self);
+        }
+    }
+
+    #[test]
+    fn it_follows_standby_redirects_to_the_active_node() {
+        let body = r#"{"ha_enabled": true, "is_self": true,
+            "leader_address": "http://active:8200",
+            "leader_cluster_address": "https://active:8201"}"#;
+        let (active, active_requests) = serve_responses(vec![format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\
+             Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        )]);
+        let (standby, _) = serve_responses(vec![redirect_response(&format!(
+            "{}/v1/sys/leader",
+            active
+        ))]);
+
+        let client = client::VaultClient::new_no_lookup(&standby[..], TOKEN).unwrap();
+        let leader = client.leader().unwrap();
+        assert!(leader.is_self);
+        let requests = active_requests.join().unwrap();
+        assert!(requests[0].starts_with("GET /v1/sys/leader "));
+        assert!(requests[0]
+            .to_lowercase()
+            .contains(&format!("x-vault-token: {}", TOKEN)));
+    }
+
+    #[test]
+    fn it_surfaces_standby_redirects_it_cannot_follow() {
+        let elsewhere = "http://127.0.0.1:1/v1/sys/leader";
+        let (active, _) = serve_responses(vec![redirect_response(elsewhere)]);
+        let (standby, _) = serve_responses(vec![redirect_response(&format!(
+            "{}/v1/sys/leader",
+            active
+        ))]);
+
+        let client = client::VaultClient::new_no_lookup(&standby[..], TOKEN).unwrap();
+        match client.leader() {
+            Err(Error::Standby(location)) => assert_eq!(location, elsewhere),
+            res => panic!("expected Standby error, received: {:?}", res),
+        }
+    }
+
+    // helper fn to serve canned HTTP `responses`, one per connection, on a
+    // local port.  Returns the server's URL and the requests it received.
+    fn serve_responses(responses: Vec<String>) -> (String, std::thread::JoinHandle<Vec<String>>) {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            responses
+                .into_iter()
+                .map(|response| {
+                    let (mut stream, _) = listener.accept().unwrap();
+                    let mut request = Vec::new();
+                    let mut buf = [0; 1024];
+                    while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                        let n = stream.read(&mut buf).unwrap();
+                        if n == 0 {
+                            break;
+                        }
+                        request.extend_from_slice(&buf[..n]);
+                    }
+                    stream.write_all(response.as_bytes()).unwrap();
+                    String::from_utf8_lossy(&request).into_owned()
+                })
+                .collect()
+        });
+        (url, handle)
+    }
+
+    // helper fn to build a standby's redirect to `location`
+    fn redirect_response(location: &str) -> String {
+        format!(
+            "HTTP/1.1 307 Temporary Redirect\r\nLocation: {}\r\n\
+             Content-Length: 0\r\nConnection: close\r\n\r\n",
+            location
+        )
+    }
+
+    #[test]
+    #[allow(deprecated)]
     fn it_can_encrypt_decrypt_transit() {
         let key_id = "test-vault-rs";
         let plaintext = b"data\0to\0encrypt";
@@ -386,6 +995,627 @@ mod tests {
         assert_eq!(plaintext, payload.as_slice());
     }
 
+    #[test]
+    fn it_can_encrypt_decrypt_transit_batches() {
+        let key_id = "test-vault-rs-batch";
+        let items = vec![b"one".to_vec(), b"two".to_vec()];
+
+        let client = Client::new(HOST, TOKEN).unwrap();
+        client
+            .transit_create_key(None, key_id, "aes256-gcm96")
+            .unwrap();
+        let mut encrypted = client.transit_encrypt_batch(None, key_id, &items).unwrap();
+        client.transit_rotate_key(None, key_id).unwrap();
+        encrypted.extend(client.transit_encrypt_batch(None, key_id, &items).unwrap());
+        assert!(encrypted[2].version > encrypted[0].version);
+        let decrypted = client
+            .transit_decrypt_batch(None, key_id, &encrypted)
+            .unwrap();
+        assert_eq!(decrypted, [&items[..], &items[..]].concat());
+
+        let bad = vec![
+            encrypted[0].clone(),
+            client::TransitCiphertext {
+                version: 1,
+                bytes: b"not a ciphertext".to_vec(),
+            },
+        ];
+        match client.transit_decrypt_batch(None, key_id, &bad) {
+            Err(e) => assert!(e.to_string().contains("Batch item 1 failed"), "{}", e),
+            Ok(_) => panic!("expected the second batch item to fail"),
+        }
+    }
+
+    #[test]
+    fn it_can_pin_the_transit_key_version() {
+        let key_id = "test-vault-rs-pinned";
+        let plaintext = b"data\0to\0pin";
+
+        let client = Client::new(HOST, TOKEN).unwrap();
+        client
+            .transit_create_key(None, key_id, "aes256-gcm96")
+            .unwrap();
+        client.transit_rotate_key(None, key_id).unwrap();
+        let latest = client.transit_read_key(None, key_id).unwrap().data.unwrap();
+
+        for version in &[1, latest.latest_version as u32] {
+            let opts = client::TransitEncryptOptions::default().key_version(*version);
+            let ciphertext = client
+                .transit_encrypt_with_options(None, key_id, plaintext, &opts)
+                .unwrap();
+            assert_eq!(ciphertext.version, *version);
+            let payload = client
+                .transit_decrypt_versioned(None, key_id, &ciphertext)
+                .unwrap();
+            assert_eq!(plaintext, payload.as_slice());
+        }
+    }
+
+    #[test]
+    fn it_can_rewrap_transit_ciphertext() {
+        let key_id = "test-vault-rs";
+        let plaintext = b"data\0to\0rewrap";
+
+        let client = Client::new(HOST, TOKEN).unwrap();
+        let encrypted = client
+            .transit_encrypt_versioned(None, key_id, plaintext)
+            .unwrap();
+        let rewrapped = client
+            .transit_rewrap(None, key_id, encrypted.to_string())
+            .unwrap();
+        assert!(rewrapped.version >= encrypted.version);
+        let payload = client
+            .transit_decrypt_versioned(None, key_id, &rewrapped)
+            .unwrap();
+        assert_eq!(plaintext, payload.as_slice());
+    }
+
+    #[test]
+    fn it_can_rotate_transit_keys() {
+        let key_id = "test-vault-rs-rotate";
+        let plaintext = b"data\0to\0rotate";
+
+        let client = Client::new(HOST, TOKEN).unwrap();
+        client
+            .transit_create_key(None, key_id, "aes256-gcm96")
+            .unwrap();
+        let before = client.transit_read_key(None, key_id).unwrap().data.unwrap();
+        let encrypted = client
+            .transit_encrypt_versioned(None, key_id, plaintext)
+            .unwrap();
+        assert_eq!(encrypted.version as u64, before.latest_version);
+
+        client.transit_rotate_key(None, key_id).unwrap();
+        // the deprecated bytes-only pair keeps working, pinned to version 1
+        #[allow(deprecated)]
+        {
+            let encrypted = client.transit_encrypt(None, key_id, plaintext).unwrap();
+            let payload = client.transit_decrypt(None, key_id, encrypted).unwrap();
+            assert_eq!(plaintext, payload.as_slice());
+        }
+        let after = client.transit_read_key(None, key_id).unwrap().data.unwrap();
+        assert_eq!(after.latest_version, before.latest_version + 1);
+        assert!(after.keys.contains_key(&after.latest_version.to_string()));
+
+        let rewrapped = client
+            .transit_rewrap(None, key_id, encrypted.to_string())
+            .unwrap();
+        assert_eq!(rewrapped.version as u64, after.latest_version);
+        let payload = client
+            .transit_decrypt_versioned(None, key_id, &rewrapped)
+            .unwrap();
+        assert_eq!(plaintext, payload.as_slice());
+
+        let config = client::TransitKeyConfig::default()
+            .min_decryption_version(after.latest_version)
+            .min_encryption_version(after.latest_version);
+        client
+            .transit_update_key_config(None, key_id, &config)
+            .unwrap();
+        let retired = client.transit_read_key(None, key_id).unwrap().data.unwrap();
+        assert_eq!(retired.min_decryption_version, after.latest_version);
+        assert_eq!(retired.min_encryption_version, after.latest_version);
+        assert!(client
+            .transit_decrypt_versioned(None, key_id, &encrypted)
+            .is_err());
+    }
+
+    #[test]
+    fn it_can_encrypt_decrypt_transit_with_context() {
+        use std::collections::HashMap;
+        let key_id = "test-vault-rs-convergent";
+        let plaintext = b"data\0to\0dedupe";
+        let client = Client::new(HOST, TOKEN).unwrap();
+        let mut key = HashMap::new();
+        let _ = key.insert("derived", true);
+        let _ = key.insert("convergent_encryption", true);
+        let _ = client
+            .write::<_, ()>(&format!("transit/keys/{}", key_id), &key)
+            .unwrap();
+
+        let first = client
+            .transit_encrypt_with_context(None, key_id, plaintext, b"tenant-a")
+            .unwrap();
+        let second = client
+            .transit_encrypt_with_context(None, key_id, plaintext, b"tenant-a")
+            .unwrap();
+        assert_eq!(first.to_string(), second.to_string());
+        let other = client
+            .transit_encrypt_with_context(None, key_id, plaintext, b"tenant-b")
+            .unwrap();
+        assert_ne!(first.to_string(), other.to_string());
+
+        let payload = client
+            .transit_decrypt_with_context(None, key_id, &first, b"tenant-a")
+            .unwrap();
+        assert_eq!(plaintext, payload.as_slice());
+        assert!(client
+            .transit_encrypt_versioned(None, key_id, plaintext)
+            .is_err());
+    }
+
+    #[test]
+    fn it_can_delete_transit_keys() {
+        let key_id = "test-vault-rs-delete";
+        let client = Client::new(HOST, TOKEN).unwrap();
+        client
+            .transit_create_key(None, key_id, "aes256-gcm96")
+            .unwrap();
+        match client.transit_delete_key(None, key_id) {
+            Err(e) => assert_eq!(e.status(), Some(StatusCode::BAD_REQUEST), "{}", e),
+            Ok(_) => panic!("expected deletion not to be allowed"),
+        }
+
+        let config = client::TransitKeyConfig::default().deletion_allowed(true);
+        client
+            .transit_update_key_config(None, key_id, &config)
+            .unwrap();
+        client.transit_delete_key(None, key_id).unwrap();
+        assert!(client.transit_read_key(None, key_id).is_err());
+    }
+
+    #[test]
+    fn it_can_export_transit_keys() {
+        use std::collections::HashMap;
+        let client = Client::new(HOST, TOKEN).unwrap();
+        let mut key = HashMap::new();
+        let _ = key.insert("exportable", true);
+        let _ = client
+            .write::<_, ()>("transit/keys/test-vault-rs-export", &key)
+            .unwrap();
+        client
+            .transit_rotate_key(None, "test-vault-rs-export")
+            .unwrap();
+
+        let keys = client
+            .transit_export_key(None, "encryption-key", "test-vault-rs-export", None)
+            .unwrap();
+        assert!(keys.contains_key("1"));
+        assert!(keys.contains_key("2"));
+        let keys = client
+            .transit_export_key(None, "encryption-key", "test-vault-rs-export", Some(1))
+            .unwrap();
+        assert_eq!(keys.len(), 1);
+        assert!(keys.contains_key("1"));
+
+        client
+            .transit_create_key(None, "test-vault-rs-no-export", "aes256-gcm96")
+            .unwrap();
+        match client.transit_export_key(None, "encryption-key", "test-vault-rs-no-export", None) {
+            Err(e) => assert_eq!(e.status(), Some(StatusCode::BAD_REQUEST), "{}", e),
+            Ok(_) => panic!("expected the key not to be exportable"),
+        }
+    }
+
+    #[test]
+    fn it_can_mount_and_unmount_secret_engines() {
+        let client = Client::new(HOST, TOKEN).unwrap();
+        let config = client::MountConfig::default()
+            .description("vault-rs test mount")
+            .default_lease_ttl(client::VaultDuration::hours(1))
+            .max_lease_ttl(client::VaultDuration::hours(2));
+        client
+            .mount_secret_engine("test-vault-rs-mount", "transit", &config)
+            .unwrap();
+
+        let mounts = client.list_mounts().unwrap();
+        let mount = &mounts["test-vault-rs-mount/"];
+        assert_eq!(mount.mount_type, "transit");
+        assert_eq!(mount.description, "vault-rs test mount");
+        assert_eq!(
+            mount.config.default_lease_ttl,
+            client::VaultDuration::hours(1)
+        );
+        assert_eq!(mount.config.max_lease_ttl, client::VaultDuration::hours(2));
+
+        let tune = client::MountTuneConfig::default()
+            .max_lease_ttl(client::VaultDuration::hours(3))
+            .audit_non_hmac_request_keys(vec!["name"]);
+        client.tune_mount("test-vault-rs-mount", &tune).unwrap();
+        let tuned = client.read_mount_tune("test-vault-rs-mount").unwrap();
+        assert_eq!(tuned.default_lease_ttl, client::VaultDuration::hours(1));
+        assert_eq!(tuned.max_lease_ttl, client::VaultDuration::hours(3));
+        assert_eq!(
+            tuned.audit_non_hmac_request_keys,
+            Some(vec!["name".to_owned()])
+        );
+
+        client.unmount("test-vault-rs-mount").unwrap();
+        assert!(!client
+            .list_mounts()
+            .unwrap()
+            .contains_key("test-vault-rs-mount/"));
+    }
+
+    #[test]
+    fn it_can_enable_and_disable_audit_devices() {
+        use std::collections::HashMap;
+        let client = Client::new(HOST, TOKEN).unwrap();
+        let mut options = HashMap::new();
+        let _ = options.insert("file_path".to_owned(), "stdout".to_owned());
+        client
+            .enable_audit_device("test-vault-rs-audit", "file", options)
+            .unwrap();
+
+        let devices = client.list_audit_devices().unwrap();
+        let device = &devices["test-vault-rs-audit/"];
+        assert_eq!(device.device_type, "file");
+        assert_eq!(device.options["file_path"], "stdout");
+
+        client.disable_audit_device("test-vault-rs-audit").unwrap();
+        assert!(!client
+            .list_audit_devices()
+            .unwrap()
+            .contains_key("test-vault-rs-audit/"));
+    }
+
+    #[test]
+    fn it_can_issue_and_revoke_pki_certificates() {
+        use std::collections::HashMap;
+        let client = Client::new(HOST, TOKEN).unwrap();
+        let mount = "test-vault-rs-pki";
+        let config = client::MountConfig::default().max_lease_ttl(client::VaultDuration::days(1));
+        client.mount_secret_engine(mount, "pki", &config).unwrap();
+
+        let mut root = HashMap::new();
+        let _ = root.insert("common_name", "example.com");
+        let _ = client
+            .write::<_, serde_json::Value>(&format!("{}/root/generate/internal", mount), &root)
+            .unwrap();
+        let mut role = HashMap::new();
+        let _ = role.insert("allowed_domains", "example.com");
+        let _ = role.insert("allow_subdomains", "true");
+        let _ = client
+            .write::<_, ()>(&format!("{}/roles/example", mount), &role)
+            .unwrap();
+
+        let opts = client::PkiIssueOptions::default()
+            .alt_names(vec!["www.example.com"])
+            .ttl(client::VaultDuration::hours(1));
+        let cert = client
+            .pki_issue(Some(mount.to_owned()), "example", "app.example.com", &opts)
+            .unwrap();
+        assert!(cert.certificate.starts_with("-----BEGIN CERTIFICATE-----"));
+        assert!(cert.private_key.contains("PRIVATE KEY"));
+        assert!(!cert.serial_number.is_empty());
+
+        let plain_serial = cert.serial_number.replace(':', "");
+        let _ = client
+            .pki_revoke(Some(mount.to_owned()), plain_serial)
+            .unwrap();
+
+        let ca = client.pki_ca_pem(Some(mount.to_owned())).unwrap();
+        assert_eq!(ca.trim(), cert.issuing_ca.trim());
+        let crl = client.pki_crl_pem(Some(mount.to_owned())).unwrap();
+        assert!(crl.starts_with("-----BEGIN X509 CRL-----"));
+
+        client.unmount(mount).unwrap();
+    }
+
+    #[test]
+    fn it_can_list_kv_v1_secrets() {
+        use std::collections::HashMap;
+        let client = Client::new(HOST, TOKEN).unwrap();
+        let mount = "test-vault-rs-kv1";
+        let config = client::MountConfig::default().options(vec![("version", "1")]);
+        client.mount_secret_engine(mount, "kv", &config).unwrap();
+
+        let mut secret = HashMap::new();
+        let _ = secret.insert("value", "world");
+        let _ = client
+            .write::<_, ()>(&format!("{}/hello/dir/fred", mount), &secret)
+            .unwrap();
+        let _ = client
+            .write::<_, ()>(&format!("{}/hello/bob", mount), &secret)
+            .unwrap();
+        let keys = client
+            .list_secrets_v1(Some(mount.to_owned()), "hello/")
+            .unwrap();
+        assert_eq!(keys, ["bob", "dir/"]);
+
+        client.unmount(mount).unwrap();
+    }
+
+    #[test]
+    fn it_caches_secrets_until_they_expire() {
+        let mut client = Client::new(HOST, TOKEN).unwrap();
+        client.enable_secret_cache(std::time::Duration::from_millis(500));
+        client.set_secret("hello_cache_ttl", "first").unwrap();
+        assert_eq!(
+            client.get_secret_cached("hello_cache_ttl").unwrap(),
+            "first"
+        );
+
+        client.set_secret("hello_cache_ttl", "second").unwrap();
+        assert_eq!(
+            client.get_secret_cached("hello_cache_ttl").unwrap(),
+            "first"
+        );
+        client.invalidate("hello_cache_ttl");
+        assert_eq!(
+            client.get_secret_cached("hello_cache_ttl").unwrap(),
+            "second"
+        );
+
+        client.set_secret("hello_cache_ttl", "third").unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(600));
+        assert_eq!(
+            client.get_secret_cached("hello_cache_ttl").unwrap(),
+            "third"
+        );
+    }
+
+    #[test]
+    fn it_caches_secrets_per_secret_backend() {
+        let mut client = Client::new(HOST, TOKEN).unwrap();
+        let mount = "test-vault-rs-cache";
+        let config = client::MountConfig::default().options(vec![("version", "2")]);
+        client.mount_secret_engine(mount, "kv", &config).unwrap();
+
+        client.enable_secret_cache(std::time::Duration::from_secs(60));
+        client.set_secret("hello_cache_mount", "default").unwrap();
+        assert_eq!(
+            client.get_secret_cached("hello_cache_mount").unwrap(),
+            "default"
+        );
+        client.secret_backend(mount);
+        client.set_secret("hello_cache_mount", "other").unwrap();
+        assert_eq!(
+            client.get_secret_cached("hello_cache_mount").unwrap(),
+            "other"
+        );
+
+        client.unmount(mount).unwrap();
+    }
+
+    #[test]
+    fn it_caches_secrets_per_namespace() {
+        let root = Client::new(HOST, TOKEN).unwrap();
+        let mut client = Client::new(HOST, TOKEN).unwrap();
+        client.enable_secret_cache(std::time::Duration::from_secs(60));
+        root.set_secret("hello_cache_namespace", "first").unwrap();
+        assert_eq!(
+            client.get_secret_cached("hello_cache_namespace").unwrap(),
+            "first"
+        );
+        root.set_secret("hello_cache_namespace", "second").unwrap();
+        // the secret must be read again, whether or not the server supports
+        // namespaces
+        client.set_namespace("team-a");
+        assert_ne!(
+            client.get_secret_cached("hello_cache_namespace").ok(),
+            Some("first".to_owned())
+        );
+        client.clear_namespace();
+        assert_eq!(
+            client.get_secret_cached("hello_cache_namespace").unwrap(),
+            "first"
+        );
+    }
+
+    #[test]
+    fn it_can_lookup_identity_entities() {
+        let client = Client::new(HOST, TOKEN).unwrap();
+        let name = "test-vault-rs-entity";
+        let body = serde_json::json!({ "name": name, "policies": ["default"] });
+        let _ = client
+            .write::<_, serde_json::Value>("identity/entity", &body)
+            .unwrap();
+
+        let by_name = client.lookup_entity_by_name(name).unwrap().data.unwrap();
+        assert_eq!(by_name.name, name);
+        assert_eq!(by_name.policies, Some(vec!["default".to_owned()]));
+        let by_id = client
+            .lookup_entity_by_id(&by_name.id)
+            .unwrap()
+            .data
+            .unwrap();
+        assert_eq!(by_id.name, name);
+
+        let _: EndpointResponse<()> = client
+            .call_endpoint(
+                DELETE,
+                &format!("identity/entity/name/{}", name),
+                None,
+                None,
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn it_can_generate_and_validate_totp_codes() {
+        use std::collections::HashMap;
+        let client = Client::new(HOST, TOKEN).unwrap();
+        let mount = "test-vault-rs-totp";
+        client
+            .mount_secret_engine(mount, "totp", &client::MountConfig::default())
+            .unwrap();
+
+        let mut key = HashMap::new();
+        let _ = key.insert(
+            "url",
+            "otpauth://totp/Vault:test@example.com?secret=Y64VEVMBTSXCYIWRSHRNDZW62MPGVU2G&issuer=Vault",
+        );
+        let _ = client
+            .write::<_, ()>(&format!("{}/keys/test", mount), &key)
+            .unwrap();
+
+        let code = client.totp_code(Some(mount.to_owned()), "test").unwrap();
+        assert_eq!(code.len(), 6);
+        assert!(client
+            .totp_validate(Some(mount.to_owned()), "test", &code)
+            .unwrap());
+
+        client.unmount(mount).unwrap();
+    }
+
+    #[test]
+    fn it_can_generate_ssh_credentials() {
+        use std::collections::HashMap;
+        let client = Client::new(HOST, TOKEN).unwrap();
+        let mount = "test-vault-rs-ssh";
+        client
+            .mount_secret_engine(mount, "ssh", &client::MountConfig::default())
+            .unwrap();
+
+        let mut role = HashMap::new();
+        let _ = role.insert("key_type", "otp");
+        let _ = role.insert("default_user", "ubuntu");
+        let _ = role.insert("cidr_list", "10.0.0.0/8");
+        let _ = client
+            .write::<_, ()>(&format!("{}/roles/otp", mount), &role)
+            .unwrap();
+
+        let otp = client
+            .ssh_otp(Some(mount.to_owned()), "otp", "10.0.0.5", None)
+            .unwrap();
+        assert_eq!(otp.key_type, "otp");
+        assert_eq!(otp.username, "ubuntu");
+        assert_eq!(otp.ip, "10.0.0.5");
+        assert!(!otp.key.is_empty());
+
+        let _ = client
+            .write::<_, serde_json::Value>(
+                &format!("{}/config/ca", mount),
+                &serde_json::json!({ "generate_signing_key": true }),
+            )
+            .unwrap();
+        let mut role = HashMap::new();
+        let _ = role.insert("key_type", "ca");
+        let _ = role.insert("allow_user_certificates", "true");
+        let _ = role.insert("allowed_users", "*");
+        let _ = client
+            .write::<_, ()>(&format!("{}/roles/ca", mount), &role)
+            .unwrap();
+        let ca_key = client
+            .read::<serde_json::Value>(&format!("{}/config/ca", mount))
+            .unwrap()
+            .data
+            .unwrap()["public_key"]
+            .as_str()
+            .unwrap()
+            .to_owned();
+
+        let opts = client::SshSignOptions::default()
+            .valid_principals(vec!["ubuntu"])
+            .ttl(client::VaultDuration::hours(1));
+        let signed = client
+            .ssh_sign(Some(mount.to_owned()), "ca", ca_key, &opts)
+            .unwrap();
+        assert!(signed.signed_key.starts_with("ssh-"));
+        assert!(!signed.serial_number.is_empty());
+
+        client.unmount(mount).unwrap();
+    }
+
+    #[test]
+    fn it_tracks_database_creds_leases() {
+        let res: client::VaultResponse<client::DatabaseCreds> = serde_json::from_str(
+            r#"{
+                "request_id": "1b4b4c8e-3f3a-4b4e-9b9b-2f6e3c1d1c1a",
+                "lease_id": "database/creds/readonly/abcdef",
+                "renewable": true,
+                "lease_duration": 3600,
+                "data": {"username": "v-token-readonly", "password": "secret"},
+                "warnings": null,
+                "auth": null,
+                "wrap_info": null
+            }"#,
+        )
+        .unwrap();
+        let before = std::time::Instant::now();
+        let lease: client::DatabaseCredsLease = res.try_into().unwrap();
+        assert_eq!(lease.lease_id, "database/creds/readonly/abcdef");
+        assert_eq!(lease.creds.username, "v-token-readonly");
+        assert!(lease.renewable);
+        assert!(lease.expire_time() >= before + std::time::Duration::from_secs(3600));
+    }
+
+    #[test]
+    fn it_parses_responses_without_a_request_id() {
+        let res: client::VaultResponse<()> =
+            serde_json::from_str(r#"{"lease_id": "", "data": null}"#).unwrap();
+        assert_eq!(res.request_id, None);
+
+        let res: client::VaultResponse<()> =
+            serde_json::from_str(r#"{"request_id": "1b4b4c8e", "data": null}"#).unwrap();
+        assert_eq!(res.request_id.as_deref(), Some("1b4b4c8e"));
+    }
+
+    #[test]
+    fn it_parses_transit_ciphertext_versions() {
+        let v2: client::TransitCiphertext = "vault:v2:AmFmYWJjYlVk".parse().unwrap();
+        assert_eq!(v2.version, 2);
+        assert_eq!(v2.bytes, b"\x02afabcbUd");
+
+        let v10: client::TransitCiphertext = "vault:v10:AmFmYWJjYlVk".parse().unwrap();
+        assert_eq!(v10.version, 10);
+        assert_eq!(v10.bytes, b"\x02afabcbUd");
+        assert_eq!(v10.to_string(), "vault:v10:AmFmYWJjYlVk");
+
+        assert!("vault:vX:AmFmYWJjYlVk"
+            .parse::<client::TransitCiphertext>()
+            .is_err());
+        assert!("AmFmYWJjYlVk".parse::<client::TransitCiphertext>().is_err());
+    }
+
+    #[tokio::test]
+    async fn it_can_use_the_async_client() {
+        let client = client::AsyncVaultClient::new(HOST, TOKEN).await.unwrap();
+        client.set_secret("hello_async", "world").await.unwrap();
+        assert_eq!(client.get_secret("hello_async").await.unwrap(), "world");
+        client.delete_secret("hello_async").await.unwrap();
+        match client.get_secret("hello_async").await {
+            Err(Error::NotFound(_)) => {}
+            res => panic!("expected NotFound, received: {:?}", res),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_maps_non_vault_error_bodies_alike_in_both_clients() {
+        // requests outside the API get a plain text 404 rather than vault's
+        // structured errors
+        let mut async_client = client::AsyncVaultClient::new_no_lookup(HOST, TOKEN).unwrap();
+        async_client.set_api_prefix("/v2");
+        let async_err = async_client.lookup().await.unwrap_err();
+        let blocking_err = tokio::task::spawn_blocking(|| {
+            let mut blocking = Client::new_no_lookup(HOST, TOKEN).unwrap();
+            blocking.set_api_prefix("/v2");
+            blocking.lookup().unwrap_err()
+        })
+        .await
+        .unwrap();
+        for err in [async_err, blocking_err].iter() {
+            match *err {
+                Error::VaultApi { status, ref errors } => {
+                    assert_eq!(status, 404);
+                    assert_eq!(errors.len(), 1);
+                }
+                ref e => panic!("expected VaultApi error, received: {:?}", e),
+            }
+        }
+    }
+
     // helper fn to panic on empty responses
     fn panic_non_empty(res: &EndpointResponse<()>) {
         match *res {